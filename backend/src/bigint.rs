@@ -0,0 +1,673 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Shl, Shr, Sub};
+
+/// Minimal arbitrary-precision unsigned integer.
+///
+/// Limbs are stored little-endian in 32-bit blocks (the same block size `BitField` uses)
+/// and are always normalized, i.e. the most significant limb is never zero.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+const LIMB_BITS: usize = 32;
+
+// Largest power of 10 that fits into a limb, used for chunked decimal conversions
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        BigUint::from_u64(1)
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut res = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        res.normalize();
+        res
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        let mut res = BigUint {
+            limbs: vec![
+                value as u32,
+                (value >> 32) as u32,
+                (value >> 64) as u32,
+                (value >> 96) as u32,
+            ],
+        };
+        res.normalize();
+        res
+    }
+
+    /// `2^exponent`
+    pub fn power_of_two(exponent: usize) -> Self {
+        let mut limbs = vec![0u32; exponent / LIMB_BITS + 1];
+        limbs[exponent / LIMB_BITS] = 1 << (exponent % LIMB_BITS);
+        BigUint { limbs }
+    }
+
+    /// `base^exponent` by square-and-multiply
+    pub fn pow(base: u32, exponent: u64) -> Self {
+        BigUint::from_u64(base as u64).power(exponent)
+    }
+
+    /// `self^exponent` by square-and-multiply
+    pub fn power(&self, mut exponent: u64) -> Self {
+        let mut result = BigUint::one();
+        let mut square = self.clone();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &square;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                square = &square * &square;
+            }
+        }
+
+        result
+    }
+
+    /// Parses a string of decimal digits. Returns `None` on any other character.
+    pub fn parse_decimal(s: &str) -> Option<Self> {
+        if s.is_empty() || !s.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut result = BigUint::zero();
+        let head = s.len() % DECIMAL_CHUNK_DIGITS;
+
+        let mut start = 0;
+        if head > 0 {
+            result = BigUint::from_u64(s[..head].parse::<u64>().unwrap());
+            start = head;
+        }
+        while start < s.len() {
+            let chunk = s[start..start + DECIMAL_CHUNK_DIGITS]
+                .parse::<u32>()
+                .unwrap();
+            result.mul_add_small(DECIMAL_CHUNK, chunk);
+            start += DECIMAL_CHUNK_DIGITS;
+        }
+
+        Some(result)
+    }
+
+    /// Parses a string of hexadecimal digits. Returns `None` on any other character.
+    pub fn parse_hex(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            return None;
+        }
+
+        let mut result = BigUint::zero();
+        for c in s.chars() {
+            let digit = c.to_digit(16)?;
+            result.mul_add_small(16, digit);
+        }
+
+        Some(result)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_odd(&self) -> bool {
+        self.get_bit(0)
+    }
+
+    /// Number of significant bits, `0` for zero
+    pub fn bit_len(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * LIMB_BITS - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn get_bit(&self, pos: usize) -> bool {
+        let block_index = pos / LIMB_BITS;
+        if block_index >= self.limbs.len() {
+            return false;
+        }
+        (self.limbs[block_index] >> (pos % LIMB_BITS)) & 1 != 0
+    }
+
+    pub fn set_bit(&mut self, pos: usize, value: bool) {
+        let block_index = pos / LIMB_BITS;
+        if block_index >= self.limbs.len() {
+            if !value {
+                return;
+            }
+            self.limbs.resize(block_index + 1, 0);
+        }
+        if value {
+            self.limbs[block_index] |= 1 << (pos % LIMB_BITS);
+        } else {
+            self.limbs[block_index] &= !(1 << (pos % LIMB_BITS));
+        }
+        self.normalize();
+    }
+
+    /// Keeps only the lowest `bits` bits, i.e. `self mod 2^bits`
+    pub fn low_bits(&self, bits: usize) -> BigUint {
+        let full_limbs = bits / LIMB_BITS;
+        if full_limbs >= self.limbs.len() {
+            return self.clone();
+        }
+
+        let mut limbs = self.limbs[..full_limbs].to_vec();
+        let rest = bits % LIMB_BITS;
+        if rest > 0 {
+            limbs.push(self.limbs[full_limbs] & ((1u32 << rest) - 1));
+        }
+
+        let mut res = BigUint { limbs };
+        res.normalize();
+        res
+    }
+
+    /// Number of trailing zero bits, `0` for zero
+    pub fn trailing_zeros(&self) -> usize {
+        for (i, limb) in self.limbs.iter().enumerate() {
+            if *limb != 0 {
+                return i * LIMB_BITS + limb.trailing_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    /// Value of the lowest 64 bits
+    pub fn low_u64(&self) -> u64 {
+        let lo = self.limbs.first().copied().unwrap_or(0) as u64;
+        let hi = self.limbs.get(1).copied().unwrap_or(0) as u64;
+        lo | (hi << 32)
+    }
+
+    /// Closest `f64` to the value, saturating to infinity
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bit_len();
+        if bits <= 64 {
+            return self.low_u64() as f64;
+        }
+        // Keep 64 top bits plus a sticky bit so that the conversion rounds correctly
+        let shift = bits - 64;
+        let top = (self >> shift).low_u64();
+        let sticky = self.trailing_zeros() < shift;
+        let value = (top | sticky as u64) as f64;
+        if shift > i32::MAX as usize {
+            return f64::INFINITY;
+        }
+        value * 2f64.powi(shift as i32)
+    }
+
+    /// Approximate base-2 logarithm, negative infinity for zero. Unlike `to_f64().log2()`
+    /// it stays finite for values beyond the `f64` range.
+    pub fn log2(&self) -> f64 {
+        let bits = self.bit_len();
+        if bits <= 64 {
+            return (self.low_u64() as f64).log2();
+        }
+        let shift = bits - 64;
+        ((self >> shift).low_u64() as f64).log2() + shift as f64
+    }
+
+    pub fn mul_small(&self, factor: u32) -> BigUint {
+        let mut res = self.clone();
+        res.mul_add_small(factor, 0);
+        res
+    }
+
+    /// `self = self * factor + addend`
+    pub fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in self.limbs.iter_mut() {
+            let v = *limb as u64 * factor as u64 + carry;
+            *limb = v as u32;
+            carry = v >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    /// Returns `(self / divisor, self % divisor)`
+    pub fn divrem_small(&self, divisor: u32) -> (BigUint, u32) {
+        assert_ne!(divisor, 0);
+
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut rem = 0u64;
+        for i in (0..self.limbs.len()).rev() {
+            let cur = (rem << 32) | self.limbs[i] as u64;
+            quotient[i] = (cur / divisor as u64) as u32;
+            rem = cur % divisor as u64;
+        }
+
+        let mut quotient = BigUint { limbs: quotient };
+        quotient.normalize();
+        (quotient, rem as u32)
+    }
+
+    /// Returns `(self / divisor, self % divisor)`
+    pub fn divrem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "division by zero");
+
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let (q, r) = self.divrem_small(divisor.limbs[0]);
+            return (q, BigUint::from_u64(r as u64));
+        }
+
+        // Knuth, TAOCP vol. 2, 4.3.1, algorithm D
+        let shift = divisor.limbs.last().unwrap().leading_zeros() as usize;
+        let v = (divisor << shift).limbs;
+        let mut u = (self << shift).limbs;
+        u.push(0);
+
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let mut quotient = vec![0u32; m + 1];
+
+        let v_top = v[n - 1] as u64;
+        let v_next = v[n - 2] as u64;
+
+        for j in (0..=m).rev() {
+            let num = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+            let mut q_hat = num / v_top;
+            let mut r_hat = num % v_top;
+
+            while q_hat > u32::MAX as u64 || q_hat * v_next > ((r_hat << 32) | u[j + n - 2] as u64)
+            {
+                q_hat -= 1;
+                r_hat += v_top;
+                if r_hat > u32::MAX as u64 {
+                    break;
+                }
+            }
+
+            // u[j..=j+n] -= q_hat * v
+            let mut borrow = 0i64;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let p = q_hat * v[i] as u64 + carry;
+                carry = p >> 32;
+                let t = u[i + j] as i64 - borrow - (p & 0xFFFF_FFFF) as i64;
+                u[i + j] = t as u32;
+                borrow = if t < 0 { 1 } else { 0 };
+            }
+            let t = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = t as u32;
+
+            if t < 0 {
+                // q_hat was one too large, add the divisor back
+                q_hat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    let s = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = s as u32;
+                    carry = s >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+
+            quotient[j] = q_hat as u32;
+        }
+
+        let mut quotient = BigUint { limbs: quotient };
+        quotient.normalize();
+
+        u.truncate(n);
+        let mut rem = BigUint { limbs: u };
+        rem.normalize();
+
+        (quotient, &rem >> shift)
+    }
+
+    /// Integer square root, i.e. `floor(sqrt(self))`
+    pub fn sqrt(&self) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+
+        // Newton iteration starting from a power of two above the root
+        let mut x = BigUint::power_of_two(self.bit_len().div_ceil(2));
+        loop {
+            let y = &(&x + &self.divrem(&x).0) >> 1;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    fn normalize(&mut self) {
+        while let Some(&0) = self.limbs.last() {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (q, r) = rest.divrem_small(DECIMAL_CHUNK);
+            chunks.push(r);
+            rest = q;
+        }
+
+        let mut res = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            res += &format!("{:09}", chunk);
+        }
+
+        write!(f, "{}", res)
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0u64;
+        for i in 0..long.limbs.len() {
+            let s = long.limbs[i] as u64 + short.limbs.get(i).copied().unwrap_or(0) as u64 + carry;
+            limbs.push(s as u32);
+            carry = s >> 32;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+
+        BigUint { limbs }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if `rhs > self`
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(*self >= *rhs, "BigUint subtraction underflow");
+
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for i in 0..self.limbs.len() {
+            let d = self.limbs[i] as i64 - rhs.limbs.get(i).copied().unwrap_or(0) as i64 - borrow;
+            if d < 0 {
+                limbs.push((d + (1i64 << 32)) as u32);
+                borrow = 1;
+            } else {
+                limbs.push(d as u32);
+                borrow = 0;
+            }
+        }
+
+        let mut res = BigUint { limbs };
+        res.normalize();
+        res
+    }
+}
+
+impl Sub for BigUint {
+    type Output = BigUint;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let v = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = v as u32;
+                carry = v >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+
+        let mut res = BigUint { limbs };
+        res.normalize();
+        res
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, bits: usize) -> Self::Output {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+
+        let limb_shift = bits / LIMB_BITS;
+        let bit_shift = bits % LIMB_BITS;
+
+        let mut limbs = vec![0u32; limb_shift];
+        if bit_shift == 0 {
+            limbs.extend_from_slice(&self.limbs);
+        } else {
+            let mut carry = 0u32;
+            for &limb in self.limbs.iter() {
+                limbs.push((limb << bit_shift) | carry);
+                carry = limb >> (LIMB_BITS - bit_shift);
+            }
+            if carry > 0 {
+                limbs.push(carry);
+            }
+        }
+
+        BigUint { limbs }
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, bits: usize) -> Self::Output {
+        &self << bits
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, bits: usize) -> Self::Output {
+        let limb_shift = bits / LIMB_BITS;
+        let bit_shift = bits % LIMB_BITS;
+
+        if limb_shift >= self.limbs.len() {
+            return BigUint::zero();
+        }
+
+        let src = &self.limbs[limb_shift..];
+        let mut limbs = Vec::with_capacity(src.len());
+        if bit_shift == 0 {
+            limbs.extend_from_slice(src);
+        } else {
+            for i in 0..src.len() {
+                let hi = src.get(i + 1).copied().unwrap_or(0);
+                limbs.push((src[i] >> bit_shift) | (hi << (LIMB_BITS - bit_shift)));
+            }
+        }
+
+        let mut res = BigUint { limbs };
+        res.normalize();
+        res
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, bits: usize) -> Self::Output {
+        &self >> bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigUint {
+        BigUint::parse_decimal(s).unwrap()
+    }
+
+    #[test]
+    fn test_decimal_roundtrip() {
+        for s in [
+            "0",
+            "1",
+            "4294967295",
+            "4294967296",
+            "18446744073709551616",
+            "340282366920938463463374607431768211456",
+            "1000000000000000000000000000000000000000000000000000000000001",
+        ] {
+            assert_eq!(big(s).to_string(), s);
+        }
+        assert_eq!(BigUint::parse_decimal("12a"), None);
+        assert_eq!(BigUint::parse_decimal(""), None);
+        assert_eq!(
+            BigUint::parse_hex("ffffffffff").unwrap().to_string(),
+            "1099511627775"
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = big("123456789012345678901234567890");
+        let b = big("987654321098765432109876543210");
+        assert_eq!((&a + &b).to_string(), "1111111110111111111011111111100");
+        assert_eq!((&b - &a).to_string(), "864197532086419753208641975320");
+        assert_eq!(
+            (&a * &b).to_string(),
+            "121932631137021795226185032733622923332237463801111263526900"
+        );
+        assert_eq!(
+            BigUint::pow(10, 30).to_string(),
+            format!("1{}", "0".repeat(30))
+        );
+        assert_eq!(BigUint::power_of_two(100), BigUint::pow(2, 100));
+        assert_eq!((&BigUint::one() << 100).bit_len(), 101);
+        assert_eq!((&BigUint::power_of_two(100) >> 99).to_string(), "2");
+        assert_eq!(BigUint::power_of_two(70).trailing_zeros(), 70);
+        assert_eq!(big("1000").low_bits(5).to_string(), "8");
+        assert_eq!(BigUint::power_of_two(64).low_bits(64), BigUint::zero());
+        assert_eq!(big("7").low_bits(64).to_string(), "7");
+    }
+
+    #[test]
+    fn test_division() {
+        let a = big("121932631137021795226185032733622923332237463801111263526900");
+        let b = big("987654321098765432109876543210");
+        let (q, r) = a.divrem(&b);
+        assert_eq!(q.to_string(), "123456789012345678901234567890");
+        assert!(r.is_zero());
+
+        let (q, r) = (&a + &BigUint::from_u64(12345)).divrem(&b);
+        assert_eq!(q.to_string(), "123456789012345678901234567890");
+        assert_eq!(r.to_string(), "12345");
+
+        // Divisor with a small top limb exercises the q_hat correction path
+        let n = &BigUint::power_of_two(200) - &BigUint::one();
+        let d = &BigUint::power_of_two(64) + &BigUint::one();
+        let (q, r) = n.divrem(&d);
+        assert_eq!(&(&q * &d) + &r, n);
+        assert!(r < d);
+
+        let (q, r) = BigUint::from_u64(100).divrem_small(7);
+        assert_eq!((q.to_string(), r), ("14".to_string(), 2));
+    }
+
+    #[test]
+    fn test_power_and_log2() {
+        assert_eq!(BigUint::from_u64(7).power(0), BigUint::one());
+        assert_eq!(
+            BigUint::from_u64(12345).power(3).to_string(),
+            "1881365963625"
+        );
+        assert_eq!(BigUint::pow(2, 100), BigUint::power_of_two(100));
+
+        assert_eq!(BigUint::zero().log2(), f64::NEG_INFINITY);
+        assert_eq!(BigUint::from_u64(1024).log2(), 10.0);
+        assert_eq!(BigUint::power_of_two(5000).log2(), 5000.0);
+        assert!((BigUint::pow(10, 1000).log2() - 1000.0 * std::f64::consts::LOG2_10).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sqrt_and_f64() {
+        assert_eq!(BigUint::from_u64(99).sqrt().to_string(), "9");
+        assert_eq!(BigUint::from_u64(100).sqrt().to_string(), "10");
+        assert_eq!(
+            BigUint::power_of_two(200).sqrt(),
+            BigUint::power_of_two(100)
+        );
+        assert_eq!(BigUint::from_u64(12345).to_f64(), 12345.0);
+        assert_eq!(BigUint::power_of_two(1000).to_f64(), 2f64.powi(1000));
+    }
+}
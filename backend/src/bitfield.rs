@@ -1,3 +1,5 @@
+use crate::bigint::BigUint;
+use crate::error::Ieee754Error;
 use core::ops::Add;
 use std::fmt::Display;
 use std::ops::{AddAssign, Bound, RangeBounds};
@@ -37,7 +39,7 @@ impl Add for &BitField {
     type Output = BitField;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.concat(&rhs)
+        self.concat(rhs)
     }
 }
 
@@ -72,46 +74,43 @@ impl BitField {
     }
 
     pub fn make_all_ones(size: usize) -> BitField {
-        assert!(size < BitField::max_size());
+        assert!(size <= BitField::max_size());
 
         let mut data = [0u32; 8];
 
         let blocks_count = size / BitField::block_size();
         let last_ones = size % BitField::block_size();
 
-        if blocks_count > 0 {
-            for i in 0..blocks_count {
-                let all_ones = u32::MAX;
-                data[i] = all_ones;
-            }
+        for block in data.iter_mut().take(blocks_count) {
+            *block = u32::MAX;
+        }
+        if last_ones > 0 {
             data[blocks_count] = (1u32 << last_ones) - 1;
-        } else {
-            data[0] = (1u32 << last_ones) - 1;
         }
 
         BitField { data, size }
     }
 
     pub fn make_u8(number: u8, size: usize) -> BitField {
-        assert!(size < BitField::max_size());
+        assert!(size <= BitField::max_size());
         let data = [number as u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32];
         BitField { data, size }
     }
 
     pub fn make_u16(number: u16, size: usize) -> BitField {
-        assert!(size < BitField::max_size());
+        assert!(size <= BitField::max_size());
         let data = [number as u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32];
         BitField { data, size }
     }
 
     pub fn make_u32(number: u32, size: usize) -> BitField {
-        assert!(size < BitField::max_size());
+        assert!(size <= BitField::max_size());
         let data = [number, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32];
         BitField { data, size }
     }
 
     pub fn make_u64(number: u64, size: usize) -> BitField {
-        assert!(size < BitField::max_size());
+        assert!(size <= BitField::max_size());
         let data = [
             (number & 0x00000000FFFFFFFF) as u32,
             (number >> 32) as u32,
@@ -126,7 +125,7 @@ impl BitField {
     }
 
     pub fn make_u128(number: u128, size: usize) -> BitField {
-        assert!(size < BitField::max_size());
+        assert!(size <= BitField::max_size());
         let data = [
             (number & 0x000000000000000000000000FFFFFFFF) as u32,
             ((number & 0x0000000000000000FFFFFFFF00000000) >> 32) as u32,
@@ -168,7 +167,7 @@ impl BitField {
 
     pub fn concat(&self, other: &BitField) -> BitField {
         let total_bits = self.size + other.size;
-        assert!(total_bits < BitField::max_size());
+        assert!(total_bits <= BitField::max_size());
 
         let mut result = BitField::new(total_bits);
 
@@ -210,7 +209,7 @@ impl BitField {
             ResizePolicy::AffectLowBits => {
                 if diff < 0 {
                     for i in 0..new_size {
-                        self.set_bit(i, self.get_bit(i + diff.abs() as usize));
+                        self.set_bit(i, self.get_bit(i + diff.unsigned_abs()));
                     }
                     for i in new_size..BitField::max_size() {
                         self.set_bit(i, false);
@@ -235,12 +234,20 @@ impl BitField {
         self.size = new_size;
     }
 
-    pub fn parse(s: &str) -> Option<BitField> {
+    pub fn parse(s: &str) -> Result<BitField, Ieee754Error> {
         BitField::parse_with_size(s, s.len())
     }
 
-    pub fn parse_with_size(s: &str, size: usize) -> Option<BitField> {
-        assert!(size <= BitField::max_size());
+    /// Parses a string of `0` and `1`. Shorter strings are zero-extended, the high bits of
+    /// longer ones are dropped.
+    pub fn parse_with_size(s: &str, size: usize) -> Result<BitField, Ieee754Error> {
+        if size > BitField::max_size() {
+            return Err(Ieee754Error::OutOfRange(format!(
+                "{} bits exceed the maximum bit field size of {}",
+                size,
+                BitField::max_size()
+            )));
+        }
 
         let mut result = BitField::new(size);
 
@@ -248,17 +255,41 @@ impl BitField {
             if c == '1' {
                 result.set_bit(i, true);
             } else if c != '0' {
-                return None;
+                return Err(Ieee754Error::InvalidSyntax {
+                    input: s.to_string(),
+                    expected: "a string of 0 and 1",
+                });
             }
         }
 
-        Some(result)
+        Ok(result)
+    }
+
+    pub fn from_biguint(value: &BigUint, size: usize) -> BitField {
+        assert!(size <= BitField::max_size());
+
+        let mut result = BitField::new(size);
+        for i in 0..size {
+            result.set_bit_unchecked(i, value.get_bit(i));
+        }
+        result
+    }
+
+    pub fn to_biguint(self) -> BigUint {
+        let mut result = BigUint::zero();
+        for i in 0..self.size {
+            if self.get_bit(i) {
+                result.set_bit(i, true);
+            }
+        }
+        result
     }
 
     pub fn all_bits_are(&self, bit: bool) -> bool {
         self.all_bits_in_range_are(0..self.size, bit)
     }
 
+    /// Start and exclusive end of `range`, which may be empty
     fn process_external_range<R>(&self, range: R) -> (usize, usize)
     where
         R: RangeBounds<usize> + std::iter::Iterator<Item = usize>,
@@ -269,9 +300,9 @@ impl BitField {
             Bound::Unbounded => 0usize,
         };
         let end_index = match range.end_bound() {
-            Bound::Included(&v) => v,
-            Bound::Excluded(&v) => v - 1,
-            Bound::Unbounded => self.size - 1,
+            Bound::Included(&v) => v + 1,
+            Bound::Excluded(&v) => v,
+            Bound::Unbounded => self.size,
         };
 
         (start_index, end_index)
@@ -283,7 +314,7 @@ impl BitField {
     {
         let (start_index, end_index) = self.process_external_range(range);
 
-        for i in start_index..=end_index {
+        for i in start_index..end_index {
             if self.get_bit(i) != bit {
                 return false;
             }
@@ -302,7 +333,7 @@ impl BitField {
 
         let (start_index, end_index) = self.process_external_range(range);
 
-        for j in start_index..=end_index {
+        for j in start_index..end_index {
             res.set_bit_unchecked(i, self.get_bit(j));
             i += 1;
         }
@@ -313,6 +344,12 @@ impl BitField {
 }
 
 #[cfg(test)]
+// The original tests predate the clippy gate and are kept as they were written
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::clone_on_copy,
+    clippy::no_effect
+)]
 mod tests {
     use super::*;
 
@@ -394,6 +431,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            BitField::parse("0102"),
+            Err(Ieee754Error::InvalidSyntax { .. })
+        ));
+        assert!(matches!(
+            BitField::parse_with_size("1", 257),
+            Err(Ieee754Error::OutOfRange(_))
+        ));
+        assert_eq!(BitField::parse("").unwrap().size(), 0);
+    }
+
     #[test]
     fn test_resize() {
         let origin = BitField::parse("001111011001").unwrap();
@@ -429,5 +479,10 @@ mod tests {
         let sub = origin.get_sub(2..7);
         assert_eq!(sub.size(), 5);
         assert_eq!(sub.to_string(), "10110");
+
+        let empty = origin.get_sub(3..3);
+        assert_eq!(empty.size(), 0);
+        assert!(empty.all_bits_are(true) && empty.all_bits_are(false));
+        assert!(BitField::new(0).all_bits_are(false));
     }
 }
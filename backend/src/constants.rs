@@ -0,0 +1,289 @@
+use crate::bigint::BigUint;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Mathematical constants that can be materialized into any layout, named after their
+/// counterparts in `std::f64::consts`
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MathConstant {
+    /// π
+    Pi,
+    /// 2π
+    Tau,
+    /// π/2
+    FracPi2,
+    /// π/3
+    FracPi3,
+    /// π/4
+    FracPi4,
+    /// π/6
+    FracPi6,
+    /// π/8
+    FracPi8,
+    /// 1/π
+    Frac1Pi,
+    /// 2/π
+    Frac2Pi,
+    /// 2/sqrt(π)
+    Frac2SqrtPi,
+    /// sqrt(2)
+    Sqrt2,
+    /// 1/sqrt(2)
+    Frac1Sqrt2,
+    /// Euler's number e
+    E,
+    /// log2(e)
+    Log2E,
+    /// log2(10)
+    Log2Of10,
+    /// log10(e)
+    Log10E,
+    /// log10(2)
+    Log10Of2,
+    /// ln(2)
+    Ln2,
+    /// ln(10)
+    Ln10,
+}
+
+impl MathConstant {
+    pub const ALL: [MathConstant; 19] = [
+        MathConstant::Pi,
+        MathConstant::Tau,
+        MathConstant::FracPi2,
+        MathConstant::FracPi3,
+        MathConstant::FracPi4,
+        MathConstant::FracPi6,
+        MathConstant::FracPi8,
+        MathConstant::Frac1Pi,
+        MathConstant::Frac2Pi,
+        MathConstant::Frac2SqrtPi,
+        MathConstant::Sqrt2,
+        MathConstant::Frac1Sqrt2,
+        MathConstant::E,
+        MathConstant::Log2E,
+        MathConstant::Log2Of10,
+        MathConstant::Log10E,
+        MathConstant::Log10Of2,
+        MathConstant::Ln2,
+        MathConstant::Ln10,
+    ];
+
+    /// Bounds `(lo, hi)` with `lo / 2^fraction_bits < constant < hi / 2^fraction_bits`.
+    ///
+    /// Every constant is irrational, so the bounds never meet, but they get as tight as
+    /// needed with growing `fraction_bits`.
+    pub fn enclose(self, fraction_bits: usize) -> (BigUint, BigUint) {
+        let bits = fraction_bits;
+        let enclosure = match self {
+            MathConstant::Pi => pi(bits),
+            MathConstant::Tau => pi(bits).mul_small(2),
+            MathConstant::FracPi2 => pi(bits).div_small(2),
+            MathConstant::FracPi3 => pi(bits).div_small(3),
+            MathConstant::FracPi4 => pi(bits).div_small(4),
+            MathConstant::FracPi6 => pi(bits).div_small(6),
+            MathConstant::FracPi8 => pi(bits).div_small(8),
+            MathConstant::Frac1Pi => Enclosure::one(bits).div(&pi(bits), bits),
+            MathConstant::Frac2Pi => Enclosure::one(bits).mul_small(2).div(&pi(bits), bits),
+            MathConstant::Frac2SqrtPi => Enclosure::one(bits)
+                .mul_small(2)
+                .div(&pi(bits).sqrt(bits), bits),
+            MathConstant::Sqrt2 => sqrt2(bits),
+            MathConstant::Frac1Sqrt2 => sqrt2(bits).div_small(2),
+            MathConstant::E => e(bits),
+            MathConstant::Log2E => Enclosure::one(bits).div(&ln2(bits), bits),
+            MathConstant::Log2Of10 => ln10(bits).div(&ln2(bits), bits),
+            MathConstant::Log10E => Enclosure::one(bits).div(&ln10(bits), bits),
+            MathConstant::Log10Of2 => ln2(bits).div(&ln10(bits), bits),
+            MathConstant::Ln2 => ln2(bits),
+            MathConstant::Ln10 => ln10(bits),
+        };
+
+        (enclosure.lo, enclosure.hi)
+    }
+}
+
+/// Fixed-point interval: the exact value lies strictly between `lo` and `hi`, both scaled
+/// by the same power of two
+struct Enclosure {
+    lo: BigUint,
+    hi: BigUint,
+}
+
+impl Enclosure {
+    /// One is exact, so it is enclosed by its neighbouring fixed-point numbers
+    fn one(bits: usize) -> Self {
+        let one = BigUint::power_of_two(bits);
+        Enclosure {
+            lo: &one - &BigUint::one(),
+            hi: &one + &BigUint::one(),
+        }
+    }
+
+    fn mul_small(&self, factor: u32) -> Self {
+        Enclosure {
+            lo: self.lo.mul_small(factor),
+            hi: self.hi.mul_small(factor),
+        }
+    }
+
+    fn div_small(&self, divisor: u32) -> Self {
+        let (hi, remainder) = self.hi.divrem_small(divisor);
+        Enclosure {
+            lo: self.lo.divrem_small(divisor).0,
+            hi: if remainder == 0 {
+                hi
+            } else {
+                &hi + &BigUint::one()
+            },
+        }
+    }
+
+    /// Quotient of two positive enclosures
+    fn div(&self, divisor: &Enclosure, bits: usize) -> Self {
+        let (lo, _) = (&self.lo << bits).divrem(&divisor.hi);
+        let (hi, _) = (&self.hi << bits).divrem(&divisor.lo);
+        Enclosure {
+            lo,
+            hi: &hi + &BigUint::one(),
+        }
+    }
+
+    fn sqrt(&self, bits: usize) -> Self {
+        Enclosure {
+            lo: (&self.lo << bits).sqrt(),
+            hi: &(&self.hi << bits).sqrt() + &BigUint::one(),
+        }
+    }
+}
+
+/// Sum of `1 / ((2k + 1) x^(2k + 1))` for `k = 0, 1, ...`, either with alternating signs
+/// (arctan(1/x)) or all positive (artanh(1/x)), for `x >= 3`
+fn inverse_arc_series(x: u32, alternating: bool, bits: usize) -> Enclosure {
+    // Truncated powers stay less than 2 below the exact ones, so every truncated term
+    // is less than 3 below its exact value
+    let mut power = BigUint::power_of_two(bits).divrem_small(x).0;
+    let mut positive = BigUint::zero();
+    let mut negative = BigUint::zero();
+    let (mut positive_terms, mut negative_terms) = (0u32, 0u32);
+
+    let mut k = 0u32;
+    while !power.is_zero() {
+        let term = power.divrem_small(2 * k + 1).0;
+        if alternating && k % 2 == 1 {
+            negative = &negative + &term;
+            negative_terms += 1;
+        } else {
+            positive = &positive + &term;
+            positive_terms += 1;
+        }
+
+        power = power.divrem_small(x * x).0;
+        k += 1;
+    }
+
+    // The omitted tail is less than 2 in magnitude when alternating, 2.25 otherwise
+    let positive_error = BigUint::from_u64(3 * positive_terms as u64 + 3);
+    let negative_error = BigUint::from_u64(3 * negative_terms as u64 + 3);
+    Enclosure {
+        lo: &positive - &(&negative + &negative_error),
+        hi: &(&positive + &positive_error) - &negative,
+    }
+}
+
+/// π = 16 arctan(1/5) - 4 arctan(1/239) (Machin)
+fn pi(bits: usize) -> Enclosure {
+    let a = inverse_arc_series(5, true, bits).mul_small(16);
+    let b = inverse_arc_series(239, true, bits).mul_small(4);
+    Enclosure {
+        lo: &a.lo - &b.hi,
+        hi: &a.hi - &b.lo,
+    }
+}
+
+/// ln 2 = 2 artanh(1/3)
+fn ln2(bits: usize) -> Enclosure {
+    inverse_arc_series(3, false, bits).mul_small(2)
+}
+
+/// ln 10 = 3 ln 2 + ln(5/4) = 3 ln 2 + 2 artanh(1/9)
+fn ln10(bits: usize) -> Enclosure {
+    let a = ln2(bits).mul_small(3);
+    let b = inverse_arc_series(9, false, bits).mul_small(2);
+    Enclosure {
+        lo: &a.lo + &b.lo,
+        hi: &a.hi + &b.hi,
+    }
+}
+
+/// e = sum of 1/k!
+fn e(bits: usize) -> Enclosure {
+    // Same truncation argument as for the arc series: every term is less than 2 below
+    // its exact value and the omitted tail is less than 3
+    let mut term = BigUint::power_of_two(bits);
+    let mut sum = BigUint::zero();
+    let mut k = 0u32;
+    while !term.is_zero() {
+        sum = &sum + &term;
+        k += 1;
+        term = term.divrem_small(k).0;
+    }
+
+    Enclosure {
+        lo: &sum - &BigUint::one(),
+        hi: &sum + &BigUint::from_u64(2 * k as u64 + 3),
+    }
+}
+
+fn sqrt2(bits: usize) -> Enclosure {
+    let lo = BigUint::power_of_two(2 * bits + 1).sqrt();
+    Enclosure {
+        hi: &lo + &BigUint::one(),
+        lo,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enclosures() {
+        use std::f64::consts;
+
+        let expected = [
+            consts::PI,
+            consts::TAU,
+            consts::FRAC_PI_2,
+            consts::FRAC_PI_3,
+            consts::FRAC_PI_4,
+            consts::FRAC_PI_6,
+            consts::FRAC_PI_8,
+            consts::FRAC_1_PI,
+            consts::FRAC_2_PI,
+            consts::FRAC_2_SQRT_PI,
+            consts::SQRT_2,
+            consts::FRAC_1_SQRT_2,
+            consts::E,
+            consts::LOG2_E,
+            consts::LOG2_10,
+            consts::LOG10_E,
+            consts::LOG10_2,
+            consts::LN_2,
+            consts::LN_10,
+        ];
+
+        for (constant, expected) in MathConstant::ALL.into_iter().zip(expected) {
+            for bits in [16, 64, 200, 1000] {
+                let (lo, hi) = constant.enclose(bits);
+                let scale = 2f64.powi(bits as i32);
+
+                assert!(lo < hi, "{:?}", constant);
+                assert!(lo.to_f64() / scale <= expected, "{:?} {}", constant, bits);
+                assert!(hi.to_f64() / scale >= expected, "{:?} {}", constant, bits);
+                // The series error grows only linearly with the number of terms
+                assert!((&hi - &lo).bit_len() <= 20, "{:?} {}", constant, bits);
+            }
+        }
+    }
+}
@@ -0,0 +1,586 @@
+use crate::bigint::BigUint;
+use std::collections::VecDeque;
+
+// Positional notation is used for magnitudes in [1e-20, 1e20), scientific outside
+const FIXED_NOTATION_POINTS: std::ops::RangeInclusive<i64> = -19..=20;
+
+/// Exact decimal number `0.d1d2d3... * 10^point`.
+///
+/// `digits` holds the significant decimal digits (values `0..=9`) without leading or
+/// trailing zeros, so zero is represented by an empty digit list.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DecimalDigits {
+    pub is_positive: bool,
+    pub digits: Vec<u8>,
+    pub point: i64,
+}
+
+impl DecimalDigits {
+    /// Exact decimal expansion of `significand * 2^exponent`
+    pub fn from_binary(is_positive: bool, significand: &BigUint, exponent: i64) -> Self {
+        if significand.is_zero() {
+            return DecimalDigits {
+                is_positive,
+                digits: Vec::new(),
+                point: 0,
+            };
+        }
+
+        // m * 2^-k == m * 5^k * 10^-k
+        let (int, decimal_exponent) = if exponent >= 0 {
+            (significand << exponent as usize, 0i64)
+        } else {
+            (
+                significand * &BigUint::pow(5, exponent.unsigned_abs()),
+                exponent,
+            )
+        };
+
+        let mut digits: Vec<u8> = int.to_string().bytes().map(|c| c - b'0').collect();
+        let point = digits.len() as i64 + decimal_exponent;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        DecimalDigits {
+            is_positive,
+            digits,
+            point,
+        }
+    }
+
+    /// Shortest decimal that lies inside the round-to-nearest interval of
+    /// `significand * 2^exponent` (Steele & White / Burger & Dybvig free-format algorithm).
+    ///
+    /// The interval spans half the gap to each neighbour; `lower_gap_is_halved` marks values
+    /// whose predecessor is twice as close (powers of two above the subnormal range) and
+    /// `inclusive` tells whether the interval ends themselves round back to the value.
+    pub fn shortest(
+        is_positive: bool,
+        significand: &BigUint,
+        exponent: i64,
+        lower_gap_is_halved: bool,
+        inclusive: bool,
+    ) -> Self {
+        if significand.is_zero() {
+            return DecimalDigits::from_binary(is_positive, significand, exponent);
+        }
+
+        // v = r / s, upper bound = (r + m_plus) / s, lower bound = (r - m_minus) / s
+        let scale_bits = if lower_gap_is_halved { 2 } else { 1 };
+        let (mut r, mut s, mut m_plus, mut m_minus) = if exponent >= 0 {
+            let gap = BigUint::power_of_two(exponent as usize);
+            (
+                &(significand << exponent as usize) << scale_bits,
+                BigUint::power_of_two(scale_bits),
+                &gap << (scale_bits - 1),
+                gap,
+            )
+        } else {
+            (
+                significand << scale_bits,
+                BigUint::power_of_two(exponent.unsigned_abs() as usize + scale_bits),
+                BigUint::power_of_two(scale_bits - 1),
+                BigUint::one(),
+            )
+        };
+
+        // Lower estimate of ceil(log10(v)), corrected upwards below
+        let log2_lower_bound = (significand.bit_len() as i64 - 1 + exponent) as f64;
+        let mut point = (log2_lower_bound * std::f64::consts::LOG10_2 - 1e-10).ceil() as i64;
+
+        if point >= 0 {
+            s = &s * &BigUint::pow(10, point as u64);
+        } else {
+            let scale = BigUint::pow(10, point.unsigned_abs());
+            r = &r * &scale;
+            m_plus = &m_plus * &scale;
+            m_minus = &m_minus * &scale;
+        }
+
+        let reaches_upper = |r: &BigUint, m_plus: &BigUint, s: &BigUint| {
+            let upper = r + m_plus;
+            if inclusive {
+                upper >= *s
+            } else {
+                upper > *s
+            }
+        };
+
+        while reaches_upper(&r, &m_plus, &s) {
+            s = s.mul_small(10);
+            point += 1;
+        }
+
+        let mut digits = Vec::new();
+        loop {
+            let (digit, rem) = r.mul_small(10).divrem(&s);
+            let digit = digit.low_u64() as u8;
+            r = rem;
+            m_plus = m_plus.mul_small(10);
+            m_minus = m_minus.mul_small(10);
+
+            let low = if inclusive { r <= m_minus } else { r < m_minus };
+            let high = reaches_upper(&r, &m_plus, &s);
+
+            match (low, high) {
+                (false, false) => digits.push(digit),
+                (true, false) => {
+                    digits.push(digit);
+                    break;
+                }
+                (false, true) => {
+                    digits.push(digit + 1);
+                    break;
+                }
+                (true, true) => {
+                    // Both candidates round-trip, pick the closer one
+                    digits.push(if r.mul_small(2) < s { digit } else { digit + 1 });
+                    break;
+                }
+            }
+        }
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        DecimalDigits {
+            is_positive,
+            digits,
+            point,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Rounds half-to-even so that at most `count` significant digits are left.
+    /// A carry out of the top digit moves the decimal point.
+    fn round_to_digits(&mut self, count: i64) {
+        if count < 0 || self.digits.len() as i64 <= count {
+            return;
+        }
+
+        let count = count as usize;
+        let first_dropped = self.digits[count];
+        let tail_nonzero = self.digits[count + 1..].iter().any(|d| *d != 0);
+        let last_kept_odd = count > 0 && self.digits[count - 1] % 2 == 1;
+
+        let round_up = first_dropped > 5 || (first_dropped == 5 && (tail_nonzero || last_kept_odd));
+
+        self.digits.truncate(count);
+
+        if round_up {
+            let mut i = count;
+            loop {
+                if i == 0 {
+                    self.digits.insert(0, 1);
+                    self.point += 1;
+                    break;
+                }
+                i -= 1;
+                if self.digits[i] == 9 {
+                    self.digits[i] = 0;
+                } else {
+                    self.digits[i] += 1;
+                    break;
+                }
+            }
+        }
+
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+
+    fn digit_at(&self, i: i64) -> char {
+        if i >= 0 && (i as usize) < self.digits.len() {
+            (b'0' + self.digits[i as usize]) as char
+        } else {
+            '0'
+        }
+    }
+
+    fn sign_prefix(&self) -> &'static str {
+        if self.is_positive {
+            ""
+        } else {
+            "-"
+        }
+    }
+
+    /// Plain positional notation with exactly `fraction_digits` digits after the point
+    pub fn to_fixed(&self, fraction_digits: usize) -> String {
+        let mut res = self.sign_prefix().to_string();
+
+        if self.point <= 0 {
+            res.push('0');
+        } else {
+            for i in 0..self.point {
+                res.push(self.digit_at(i));
+            }
+        }
+
+        if fraction_digits > 0 {
+            res.push('.');
+            for i in 0..fraction_digits as i64 {
+                res.push(self.digit_at(self.point + i));
+            }
+        }
+
+        res
+    }
+
+    /// `d.ddd...e<exp>` notation with exactly `fraction_digits` digits after the point
+    pub fn to_scientific(&self, fraction_digits: usize) -> String {
+        let mut res = self.sign_prefix().to_string();
+
+        res.push(self.digit_at(0));
+        if fraction_digits > 0 {
+            res.push('.');
+            for i in 1..=fraction_digits as i64 {
+                res.push(self.digit_at(i));
+            }
+        }
+
+        let exponent = if self.is_zero() { 0 } else { self.point - 1 };
+        res + &format!("e{}", exponent)
+    }
+
+    /// Human-readable rendering: positional notation with up to `precision` fraction digits
+    /// for magnitudes in `[1e-20, 1e20)`, scientific notation with 8 significant digits outside.
+    pub fn format_rounded(&self, precision: u8) -> String {
+        if self.is_zero() || FIXED_NOTATION_POINTS.contains(&self.point) {
+            let mut rounded = self.clone();
+            rounded.round_to_digits(rounded.point + precision as i64);
+            if rounded.point + (precision as i64) < 0 {
+                rounded.digits.clear();
+            }
+
+            let fixed = rounded.to_fixed(precision as usize);
+            if precision == 0 {
+                return fixed;
+            }
+            fixed
+                .trim_end_matches('0')
+                .trim_end_matches('.')
+                .to_string()
+        } else {
+            let mut rounded = self.clone();
+            rounded.round_to_digits(8);
+
+            rounded
+                .to_scientific(7)
+                .replace("e0", "e")
+                .replace(".0000000e", ".0e")
+        }
+    }
+
+    /// All significant digits: positional notation for magnitudes in `[1e-20, 1e20)`,
+    /// scientific notation outside. The output always contains a decimal point.
+    pub fn format_all_digits(&self) -> String {
+        let fraction_digits = self.digits.len() as i64 - self.point;
+
+        if self.is_zero() || FIXED_NOTATION_POINTS.contains(&self.point) {
+            self.to_fixed(fraction_digits.max(1) as usize)
+        } else {
+            self.to_scientific((self.digits.len() - 1).max(1))
+        }
+    }
+}
+
+// Written exponents are clamped to this magnitude. Anything that large is far outside the
+// range of every layout, and the clamp keeps the exponent arithmetic free of overflows.
+pub(crate) const LITERAL_EXPONENT_LIMIT: i64 = 1 << 48;
+
+/// Splits an optional leading `+` or `-` off a numeric literal
+pub(crate) fn split_literal_sign(text: &str) -> (bool, &str) {
+    match text.strip_prefix('-') {
+        Some(rest) => (false, rest),
+        None => (true, text.strip_prefix('+').unwrap_or(text)),
+    }
+}
+
+/// Parses the `[+-]digits` exponent of a literal, clamped to `LITERAL_EXPONENT_LIMIT`
+pub(crate) fn parse_literal_exponent(text: &str) -> Option<i64> {
+    let (is_positive, digits) = split_literal_sign(text);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let magnitude = digits.bytes().fold(0i64, |acc, c| {
+        (acc * 10 + (c - b'0') as i64).min(LITERAL_EXPONENT_LIMIT)
+    });
+
+    Some(if is_positive { magnitude } else { -magnitude })
+}
+
+/// Decimal literal `digits * 10^exponent` exactly as written, e.g. `-6.02e23` or
+/// `0.000001`. Both the number of digits and the exponent are unbounded.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DecimalLiteral {
+    pub is_positive: bool,
+    pub digits: BigUint,
+    pub exponent: i64,
+    significant_digits: i64,
+}
+
+impl DecimalLiteral {
+    /// Parses `[+-]digits[.digits][(e|E)[+-]digits]`, where either the integer or the
+    /// fraction part may be omitted but not both
+    pub fn parse(text: &str) -> Option<Self> {
+        let (is_positive, text) = split_literal_sign(text);
+
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None => (text, None),
+        };
+
+        let (int, fraction) = match mantissa.split_once('.') {
+            Some((int, fraction)) => (int, fraction),
+            None => (mantissa, ""),
+        };
+
+        let is_digits = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+        if int.is_empty() && fraction.is_empty() || !is_digits(int) || !is_digits(fraction) {
+            return None;
+        }
+
+        let exponent = match exponent {
+            Some(exponent) => parse_literal_exponent(exponent)?,
+            None => 0,
+        };
+
+        let all_digits = [int, fraction].concat();
+        let significant = all_digits.trim_start_matches('0');
+
+        Some(DecimalLiteral {
+            is_positive,
+            digits: BigUint::parse_decimal(significant).unwrap_or_default(),
+            exponent: exponent - fraction.len() as i64,
+            significant_digits: significant.len() as i64,
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_zero()
+    }
+
+    /// Decimal exponent `m` of a non-zero literal with `10^(m-1) <= |value| < 10^m`
+    pub fn magnitude(&self) -> i64 {
+        self.exponent + self.significant_digits
+    }
+}
+
+/// Lazily generated full decimal expansion of `significand * 2^exponent` in positional
+/// notation. Every binary fraction terminates, so the iterator is finite, but for wide
+/// layouts it may yield hundreds of thousands of characters.
+pub struct ExactDecimalChars {
+    pending: VecDeque<char>,
+    fraction: BigUint,
+    fraction_bits: usize,
+}
+
+// Fraction digits are produced in chunks of 9 to amortize the big integer arithmetic
+const FRACTION_CHUNK: u32 = 1_000_000_000;
+const FRACTION_CHUNK_DIGITS: usize = 9;
+
+impl ExactDecimalChars {
+    pub fn new(is_positive: bool, significand: &BigUint, exponent: i64) -> Self {
+        let (int, fraction, fraction_bits) = if exponent >= 0 {
+            (significand << exponent as usize, BigUint::zero(), 0)
+        } else {
+            let fraction_bits = exponent.unsigned_abs() as usize;
+            (
+                significand >> fraction_bits,
+                significand.low_bits(fraction_bits),
+                fraction_bits,
+            )
+        };
+
+        let mut pending: VecDeque<char> = VecDeque::new();
+        if !is_positive {
+            pending.push_back('-');
+        }
+        pending.extend(int.to_string().chars());
+        pending.push_back('.');
+        if fraction.is_zero() {
+            pending.push_back('0');
+        }
+
+        ExactDecimalChars {
+            pending,
+            fraction,
+            fraction_bits,
+        }
+    }
+
+    /// Stream over a fixed text, used for values without a digit expansion like `NaN`
+    pub fn from_text(text: &str) -> Self {
+        ExactDecimalChars {
+            pending: text.chars().collect(),
+            fraction: BigUint::zero(),
+            fraction_bits: 0,
+        }
+    }
+
+    fn generate_fraction_chunk(&mut self) {
+        let scaled = self.fraction.mul_small(FRACTION_CHUNK);
+        let chunk = (&scaled >> self.fraction_bits).low_u64();
+        self.fraction = scaled.low_bits(self.fraction_bits);
+
+        let mut chunk = format!("{:0width$}", chunk, width = FRACTION_CHUNK_DIGITS);
+        if self.fraction.is_zero() {
+            chunk = chunk.trim_end_matches('0').to_string();
+        }
+        self.pending.extend(chunk.chars());
+    }
+}
+
+impl Iterator for ExactDecimalChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending.is_empty() && !self.fraction.is_zero() {
+            self.generate_fraction_chunk();
+        }
+        self.pending.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(significand: u64, exponent: i64) -> DecimalDigits {
+        DecimalDigits::from_binary(true, &BigUint::from_u64(significand), exponent)
+    }
+
+    #[test]
+    fn test_from_binary() {
+        let d = decimal(1, -3);
+        assert_eq!(d.digits, vec![1, 2, 5]);
+        assert_eq!(d.point, 0);
+
+        let d = decimal(3, 4);
+        assert_eq!(d.digits, vec![4, 8]);
+        assert_eq!(d.point, 2);
+
+        let d = decimal(5, 1);
+        assert_eq!(d.digits, vec![1]);
+        assert_eq!(d.point, 2);
+
+        assert!(decimal(0, 10).is_zero());
+    }
+
+    #[test]
+    fn test_format_rounded() {
+        assert_eq!(decimal(1, -3).format_rounded(4), "0.125");
+        assert_eq!(decimal(1, -3).format_rounded(2), "0.12");
+        assert_eq!(decimal(3, -3).format_rounded(2), "0.38");
+        assert_eq!(decimal(1, -1).format_rounded(0), "0");
+        assert_eq!(decimal(3, -1).format_rounded(0), "2");
+        assert_eq!(decimal(1, -70).format_rounded(4), "8.4703295e-22");
+        assert_eq!(decimal(1, 127).format_rounded(4), "1.7014118e38");
+        assert_eq!(decimal(1, 100).format_rounded(4), "1.2676506e30");
+        assert_eq!(decimal(1, -66).format_rounded(4), "0");
+        assert_eq!(
+            DecimalDigits::from_binary(false, &BigUint::from_u64(1), -66).format_rounded(4),
+            "-0"
+        );
+    }
+
+    #[test]
+    fn test_shortest() {
+        // 0.1f32 == 13421773 * 2^-27
+        let d = DecimalDigits::shortest(true, &BigUint::from_u64(13421773), -27, false, false);
+        assert_eq!(d.digits, vec![1]);
+        assert_eq!(d.point, 0);
+        assert_eq!(d.format_all_digits(), "0.1");
+
+        // 2^-1074, the smallest f64 subnormal
+        let d = DecimalDigits::shortest(true, &BigUint::one(), -1074, false, false);
+        assert_eq!(d.format_all_digits(), "5.0e-324");
+
+        let d = DecimalDigits::shortest(false, &BigUint::from_u64(3), 70, false, false);
+        assert_eq!(d.format_all_digits(), "-4.0e21");
+        assert_eq!(decimal(100, 0).format_all_digits(), "100.0");
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        let parse = |text: &str| {
+            let literal = DecimalLiteral::parse(text).unwrap();
+            (
+                literal.is_positive,
+                literal.digits.to_string(),
+                literal.exponent,
+                literal.magnitude(),
+            )
+        };
+
+        assert_eq!(parse("0"), (true, "0".to_string(), 0, 0));
+        assert_eq!(parse("-0.0"), (false, "0".to_string(), -1, -1));
+        assert_eq!(parse("123.45"), (true, "12345".to_string(), -2, 3));
+        assert_eq!(parse("+.5"), (true, "5".to_string(), -1, 0));
+        assert_eq!(parse("5."), (true, "5".to_string(), 0, 1));
+        assert_eq!(parse("0.00125"), (true, "125".to_string(), -5, -2));
+        assert_eq!(parse("6.02e23"), (true, "602".to_string(), 21, 24));
+        assert_eq!(parse("-1E-300"), (false, "1".to_string(), -300, -299));
+        assert_eq!(parse("1e+0005"), (true, "1".to_string(), 5, 6));
+        assert_eq!(
+            parse("123456789012345678901234567890123456789"),
+            (
+                true,
+                "123456789012345678901234567890123456789".to_string(),
+                0,
+                39
+            )
+        );
+
+        // Absurd exponents are clamped rather than overflowing
+        let (_, _, exponent, _) = parse("1e99999999999999999999999999");
+        assert_eq!(exponent, LITERAL_EXPONENT_LIMIT);
+        let (_, _, exponent, _) = parse("0.1e-99999999999999999999999999");
+        assert_eq!(exponent, -LITERAL_EXPONENT_LIMIT - 1);
+
+        for invalid in [
+            "", ".", "-", "e5", "1e", "1e+", "--1", "1.2.3", "1 2", "0x10", "1e5.0",
+        ] {
+            assert_eq!(DecimalLiteral::parse(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_exact_chars() {
+        let exact = |significand: u64, exponent: i64| {
+            ExactDecimalChars::new(true, &BigUint::from_u64(significand), exponent)
+                .collect::<String>()
+        };
+
+        // 0.1f32
+        assert_eq!(exact(13421773, -27), "0.100000001490116119384765625");
+        assert_eq!(exact(3, 4), "48.0");
+        assert_eq!(exact(1, -1), "0.5");
+        assert_eq!(exact(1, -9), "0.001953125");
+        assert_eq!(exact(5, -1), "2.5");
+        assert_eq!(exact(0, 0), "0.0");
+        assert_eq!(
+            ExactDecimalChars::new(false, &BigUint::from_u64(1), -20).collect::<String>(),
+            "-0.00000095367431640625"
+        );
+        assert_eq!(
+            ExactDecimalChars::from_text("NaN").collect::<String>(),
+            "NaN"
+        );
+
+        // Lazy: asking for a prefix of 2^-1074 does not expand all of its 1074 digits
+        let mut chars = ExactDecimalChars::new(true, &BigUint::one(), -1074);
+        let prefix: String = chars.by_ref().take(12).collect();
+        assert_eq!(prefix, "0.0000000000");
+        assert!(chars.fraction_bits == 1074 && !chars.fraction.is_zero());
+        assert_eq!(chars.count(), 1074 - 10);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::bitfield::BitField;
+use crate::error::Ieee754Error;
+use crate::ieee754_ops::{parse_stored_binary, FloatLayout};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// One encoding together with the layout it belongs to. Its text is the layout, a colon
+/// and the bits, in hex when they fill whole digits, e.g. `fp32:0x3f800000` or
+/// `e3m3:0b0011100`.
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq)]
+pub struct EncodedValue {
+    layout: FloatLayout,
+    bits: BitField,
+}
+
+#[wasm_bindgen]
+impl EncodedValue {
+    /// `binary` is a bit string of exactly the layout size
+    #[wasm_bindgen(constructor)]
+    pub fn new(binary: &str, layout: &FloatLayout) -> Result<EncodedValue, Ieee754Error> {
+        Ok(Self {
+            layout: *layout,
+            bits: parse_stored_binary(binary, layout)?,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn layout(&self) -> FloatLayout {
+        self.layout
+    }
+
+    /// The encoding as a bit string, the input of the conversion functions
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> String {
+        self.bits.to_string()
+    }
+
+    /// See the `FromStr` implementation
+    #[wasm_bindgen]
+    pub fn parse(text: &str) -> Result<EncodedValue, Ieee754Error> {
+        text.parse()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_compact_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl EncodedValue {
+    /// Pairs `bits` with `layout`, which has to be valid and of the same size
+    pub fn from_bits(layout: &FloatLayout, bits: BitField) -> Result<Self, Ieee754Error> {
+        layout.validate()?;
+        if bits.size() != layout.get_size() {
+            return Err(Ieee754Error::WrongWidth {
+                expected: layout.get_size(),
+                found: bits.size(),
+            });
+        }
+
+        Ok(Self {
+            layout: *layout,
+            bits,
+        })
+    }
+
+    pub fn get_bits(&self) -> BitField {
+        self.bits
+    }
+}
+
+impl std::str::FromStr for EncodedValue {
+    type Err = Ieee754Error;
+
+    /// Reads `<layout>:<bits>`, where the layout is anything `FloatLayout` parses and the
+    /// bits are `0x` and hex digits or, with an optional `0b`, a bit string. Either has to
+    /// cover exactly the layout size.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (layout, bits) =
+            text.trim()
+                .rsplit_once(':')
+                .ok_or_else(|| Ieee754Error::InvalidSyntax {
+                    input: text.to_string(),
+                    expected: "a layout and an encoding such as fp32:0x3f800000",
+                })?;
+        let layout: FloatLayout = layout.parse()?;
+        let bits = bits.trim();
+
+        let bits = match bits.strip_prefix("0x").or_else(|| bits.strip_prefix("0X")) {
+            Some(digits) => parse_hex_bits(digits, &layout)?,
+            None => parse_stored_binary(bits.strip_prefix("0b").unwrap_or(bits), &layout)?,
+        };
+
+        Ok(Self { layout, bits })
+    }
+}
+
+impl std::fmt::Display for EncodedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.layout)?;
+
+        let size = self.bits.size();
+        if !size.is_multiple_of(4) {
+            return write!(f, "0b{}", self.bits);
+        }
+
+        write!(f, "0x")?;
+        for digit in (0..size / 4).rev() {
+            let value = (0..4).fold(0, |value, bit| {
+                value | (self.bits.get_bit(4 * digit + bit) as u32) << bit
+            });
+            write!(f, "{:x}", value)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for EncodedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EncodedValue({})", self)
+    }
+}
+
+/// Reads hex digits that hold exactly one encoding of `layout`
+fn parse_hex_bits(digits: &str, layout: &FloatLayout) -> Result<BitField, Ieee754Error> {
+    layout.validate()?;
+
+    let width = 4 * digits.chars().count();
+    if width != layout.get_size() {
+        return Err(Ieee754Error::WrongWidth {
+            expected: layout.get_size(),
+            found: width,
+        });
+    }
+
+    let mut bits = BitField::new(width);
+    for (digit, c) in digits.chars().rev().enumerate() {
+        let value = c.to_digit(16).ok_or_else(|| Ieee754Error::InvalidSyntax {
+            input: digits.to_string(),
+            expected: "hex digits",
+        })?;
+        for bit in 0..4 {
+            bits.set_bit(4 * digit + bit, value & (1 << bit) != 0);
+        }
+    }
+
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754_ops::{FLOAT32_LAYOUT, FP8_E4M3FN_LAYOUT};
+
+    #[test]
+    fn test_compact_string() {
+        let one = EncodedValue::new("00111111100000000000000000000000", &FLOAT32_LAYOUT).unwrap();
+        assert_eq!(one.to_string(), "fp32:0x3f800000");
+        assert_eq!("fp32:0x3F800000".parse::<EncodedValue>().unwrap(), one);
+        assert_eq!(
+            "binary32 : 0b00111111100000000000000000000000"
+                .parse::<EncodedValue>()
+                .unwrap(),
+            one
+        );
+
+        let nan = EncodedValue::new("01111111", &FP8_E4M3FN_LAYOUT).unwrap();
+        assert_eq!(nan.to_string(), "fp8-e4m3fn:0x7f");
+        assert_eq!(nan.get_bits().to_string(), "01111111");
+
+        // Sizes that are not whole hex digits keep the bit string
+        let layout = FloatLayout::new(1, 3, 3, 3);
+        let value = EncodedValue::new("0011100", &layout).unwrap();
+        assert_eq!(value.to_string(), "e3m3:0b0011100");
+        assert_eq!(value.to_string().parse::<EncodedValue>().unwrap(), value);
+        assert_eq!(value.bits(), "0011100");
+        assert_eq!(value.layout(), layout);
+
+        assert_eq!(
+            EncodedValue::from_bits(&FLOAT32_LAYOUT, BitField::new(16)).unwrap_err(),
+            Ieee754Error::WrongWidth {
+                expected: 32,
+                found: 16
+            }
+        );
+        assert_eq!(
+            "fp16:0x3c0".parse::<EncodedValue>().unwrap_err(),
+            Ieee754Error::WrongWidth {
+                expected: 16,
+                found: 12
+            }
+        );
+        assert_eq!(
+            "fp16:0x3cg0".parse::<EncodedValue>().unwrap_err(),
+            Ieee754Error::InvalidSyntax {
+                input: "3cg0".to_string(),
+                expected: "hex digits"
+            }
+        );
+        assert!(matches!(
+            "0x3c00".parse::<EncodedValue>().unwrap_err(),
+            Ieee754Error::InvalidSyntax { .. }
+        ));
+        assert!(matches!(
+            "fp42:0x3c00".parse::<EncodedValue>().unwrap_err(),
+            Ieee754Error::InvalidSyntax { .. }
+        ));
+    }
+}
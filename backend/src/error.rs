@@ -0,0 +1,87 @@
+use std::fmt::Display;
+use wasm_bindgen::{JsError, JsValue};
+
+/// Everything that can go wrong in the conversion functions. Across wasm_bindgen it
+/// becomes a thrown JS `Error` carrying the `Display` message.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Ieee754Error {
+    /// Input text that is not what the function reads, e.g. a stray character in a
+    /// decimal or a bit string
+    InvalidSyntax {
+        input: String,
+        expected: &'static str,
+    },
+    /// Bit string whose length differs from the layout size
+    WrongWidth { expected: usize, found: usize },
+    /// Layout that cannot be used for the requested operation
+    LayoutMismatch(String),
+    /// Layout that no encoding routine can work with, e.g. one without mantissa bits
+    InvalidLayout(String),
+    /// Numeric argument outside of the supported range
+    OutOfRange(String),
+}
+
+impl Display for Ieee754Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ieee754Error::InvalidSyntax { input, expected } => {
+                write!(
+                    f,
+                    "invalid syntax: expected {}, found \"{}\"",
+                    expected, input
+                )
+            }
+            Ieee754Error::WrongWidth { expected, found } => {
+                write!(
+                    f,
+                    "wrong width: expected {} bits, found {}",
+                    expected, found
+                )
+            }
+            Ieee754Error::LayoutMismatch(reason) => write!(f, "layout mismatch: {}", reason),
+            Ieee754Error::InvalidLayout(reason) => write!(f, "invalid layout: {}", reason),
+            Ieee754Error::OutOfRange(reason) => write!(f, "out of range: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Ieee754Error {}
+
+impl From<Ieee754Error> for JsValue {
+    fn from(error: Ieee754Error) -> Self {
+        JsError::new(&error.to_string()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages() {
+        assert_eq!(
+            Ieee754Error::InvalidSyntax {
+                input: "1.2x".to_string(),
+                expected: "a number"
+            }
+            .to_string(),
+            "invalid syntax: expected a number, found \"1.2x\""
+        );
+        assert_eq!(
+            Ieee754Error::WrongWidth {
+                expected: 32,
+                found: 31
+            }
+            .to_string(),
+            "wrong width: expected 32 bits, found 31"
+        );
+        assert_eq!(
+            Ieee754Error::InvalidLayout("no mantissa".to_string()).to_string(),
+            "invalid layout: no mantissa"
+        );
+        assert_eq!(
+            Ieee754Error::OutOfRange("300 bits".to_string()).to_string(),
+            "out of range: 300 bits"
+        );
+    }
+}
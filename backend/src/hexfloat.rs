@@ -0,0 +1,136 @@
+use crate::bigint::BigUint;
+use crate::decimal::{parse_literal_exponent, split_literal_sign};
+
+/// Hexadecimal floating-point literal `digits * 2^exponent` as written in C99 or with
+/// `%a`, e.g. `0x1.8p+1` or `-0x1.fffffep127`. Every such literal is a dyadic rational,
+/// so unlike a decimal it converts without any hidden rounding.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HexLiteral {
+    pub is_positive: bool,
+    pub digits: BigUint,
+    pub exponent: i64,
+}
+
+impl HexLiteral {
+    /// Parses `[+-]0x hexdigits[.hexdigits][(p|P)[+-]digits]`. The binary exponent is
+    /// optional and either the integer or the fraction part may be omitted but not both.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (is_positive, text) = split_literal_sign(text);
+        let text = text
+            .strip_prefix("0x")
+            .or_else(|| text.strip_prefix("0X"))?;
+
+        let (mantissa, exponent) = match text.find(['p', 'P']) {
+            Some(index) => (&text[..index], parse_literal_exponent(&text[index + 1..])?),
+            None => (text, 0),
+        };
+
+        let (int, fraction) = match mantissa.split_once('.') {
+            Some((int, fraction)) => (int, fraction),
+            None => (mantissa, ""),
+        };
+
+        let is_hex_digits = |part: &str| part.bytes().all(|c| c.is_ascii_hexdigit());
+        if int.is_empty() && fraction.is_empty() || !is_hex_digits(int) || !is_hex_digits(fraction)
+        {
+            return None;
+        }
+
+        Some(HexLiteral {
+            is_positive,
+            digits: BigUint::parse_hex(&[int, fraction].concat())?,
+            exponent: exponent - 4 * fraction.len() as i64,
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_zero()
+    }
+}
+
+/// `%a`-style text of `significand * 2^(exponent - fraction_bits)`, where the significand
+/// has a single bit in front of its `fraction_bits` fraction bits. The fraction is padded
+/// to whole hex digits on the right and trailing zero digits are dropped, so 1.5 is
+/// `0x1.8p+0`, 1.0 is `0x1p+0` and a subnormal reads like `0x0.004p-14`.
+pub fn format_hex_float(
+    is_positive: bool,
+    significand: &BigUint,
+    fraction_bits: usize,
+    exponent: i64,
+) -> String {
+    let leading = (significand >> fraction_bits).low_u64();
+
+    let hex_digits = fraction_bits.div_ceil(4);
+    let fraction = &significand.low_bits(fraction_bits) << (hex_digits * 4 - fraction_bits);
+    let fraction: String = (0..hex_digits)
+        .rev()
+        .map(|digit| {
+            let nibble = (0..4).fold(0, |acc, bit| {
+                acc | (fraction.get_bit(digit * 4 + bit) as u32) << bit
+            });
+            char::from_digit(nibble, 16).unwrap()
+        })
+        .collect();
+    let fraction = fraction.trim_end_matches('0');
+
+    format!(
+        "{}0x{:x}{}{}p{:+}",
+        if is_positive { "" } else { "-" },
+        leading,
+        if fraction.is_empty() { "" } else { "." },
+        fraction,
+        if significand.is_zero() { 0 } else { exponent }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_literal() {
+        let parse = |text: &str| {
+            let literal = HexLiteral::parse(text).unwrap();
+            (
+                literal.is_positive,
+                literal.digits.to_string(),
+                literal.exponent,
+            )
+        };
+
+        assert_eq!(parse("0x1.8p+1"), (true, "24".to_string(), -3));
+        assert_eq!(
+            parse("-0x1.fffffep127"),
+            (false, "33554430".to_string(), 103)
+        );
+        assert_eq!(parse("0X.8P-1"), (true, "8".to_string(), -5));
+        assert_eq!(parse("+0x10"), (true, "16".to_string(), 0));
+        assert_eq!(parse("0x0p0"), (true, "0".to_string(), 0));
+        assert_eq!(parse("0xAbC."), (true, "2748".to_string(), 0));
+
+        for invalid in [
+            "", "0x", "0x.", "0x1p", "0x1.2.3", "1.8p1", "0x1g", "0x1e+5", "--0x1",
+        ] {
+            assert_eq!(HexLiteral::parse(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_format_hex_float() {
+        let format = |is_positive: bool, significand: u64, fraction_bits: usize, exponent: i64| {
+            format_hex_float(
+                is_positive,
+                &BigUint::from_u64(significand),
+                fraction_bits,
+                exponent,
+            )
+        };
+
+        assert_eq!(format(true, 0b11, 1, 1), "0x1.8p+1");
+        assert_eq!(format(true, 1 << 23, 23, 0), "0x1p+0");
+        assert_eq!(format(false, 0xFFFFFF, 23, 127), "-0x1.fffffep+127");
+        assert_eq!(format(true, 0b1101, 3, -6), "0x1.ap-6");
+        assert_eq!(format(true, 1, 10, -14), "0x0.004p-14");
+        assert_eq!(format(false, 0, 52, -1022), "-0x0p+0");
+    }
+}
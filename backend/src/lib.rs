@@ -1,2 +1,14 @@
-mod bitfield;
-mod ieee754_ops;
+pub mod bigint;
+pub mod bitfield;
+pub mod constants;
+pub mod decimal;
+pub mod encoded;
+pub mod error;
+pub mod hexfloat;
+pub mod ieee754_ops;
+pub mod rational;
+pub mod registry;
+pub mod rounding;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod storage;
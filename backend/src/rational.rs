@@ -0,0 +1,212 @@
+use crate::bigint::BigUint;
+use crate::decimal::{parse_literal_exponent, split_literal_sign, DecimalLiteral};
+use crate::error::Ieee754Error;
+
+// Exact evaluation gives up on numerators or denominators beyond this many bits. Values
+// that large only fit a layout as a ratio of two huge numbers, e.g. `1e100000000/1e100000000`.
+pub(crate) const EXACT_BITS_LIMIT: f64 = (1u64 << 24) as f64;
+
+/// Power `base^power` of an unsigned decimal literal, `power` being 1 when not written
+#[derive(Clone, PartialEq, Debug)]
+struct PowerTerm {
+    base: DecimalLiteral,
+    power: i64,
+}
+
+impl PowerTerm {
+    fn parse(text: &str) -> Option<Self> {
+        let (base, power) = match text.split_once('^') {
+            Some((base, power)) => (base, parse_literal_exponent(power)?),
+            None => (text, 1),
+        };
+
+        // The sign belongs to the whole term, so that `-2^2` is -4
+        if base.starts_with(['+', '-']) {
+            return None;
+        }
+
+        Some(PowerTerm {
+            base: DecimalLiteral::parse(base)?,
+            power,
+        })
+    }
+
+    fn is_zero(&self) -> bool {
+        self.base.is_zero() && self.power > 0
+    }
+
+    fn is_infinite(&self) -> bool {
+        self.base.is_zero() && self.power < 0
+    }
+
+    /// Approximate `log2` of a term that is neither zero nor infinite
+    fn log2(&self) -> f64 {
+        if self.power == 0 {
+            return 0.0;
+        }
+
+        let base = self.base.digits.log2() + self.base.exponent as f64 * std::f64::consts::LOG2_10;
+        base * self.power as f64
+    }
+
+    /// Exact `numerator / denominator` of a term that is neither zero nor infinite
+    fn to_ratio(&self) -> Result<(BigUint, BigUint), Ieee754Error> {
+        let digit_bits = self.base.digits.bit_len() as f64;
+        let scale_bits = self.base.exponent.unsigned_abs() as f64 * std::f64::consts::LOG2_10;
+        if (digit_bits + scale_bits) * self.power.unsigned_abs() as f64 > EXACT_BITS_LIMIT {
+            return Err(Ieee754Error::OutOfRange(format!(
+                "{}^{} is too large to evaluate exactly",
+                self.base.digits, self.power
+            )));
+        }
+
+        let scale = BigUint::pow(10, self.base.exponent.unsigned_abs());
+        let (numerator, denominator) = if self.base.exponent >= 0 {
+            (&self.base.digits * &scale, BigUint::one())
+        } else {
+            (self.base.digits.clone(), scale)
+        };
+
+        let power = self.power.unsigned_abs();
+        Ok(if self.power >= 0 {
+            (numerator.power(power), denominator.power(power))
+        } else {
+            (denominator.power(power), numerator.power(power))
+        })
+    }
+}
+
+/// Exact rational literal `[+-]term[/[+-]term]`, where each term is a decimal literal
+/// optionally raised to an integer power, e.g. `1/3`, `-22/7`, `2^-126` or `6.02e23/7^2`
+#[derive(Clone, PartialEq, Debug)]
+pub struct RationalLiteral {
+    is_positive: bool,
+    numerator: PowerTerm,
+    denominator: Option<PowerTerm>,
+}
+
+impl RationalLiteral {
+    pub fn parse(text: &str) -> Option<Self> {
+        let (numerator, denominator) = match text.split_once('/') {
+            Some((numerator, denominator)) => (numerator, Some(denominator)),
+            None => (text, None),
+        };
+
+        let (is_numerator_positive, numerator) = split_literal_sign(numerator);
+        let (is_denominator_positive, denominator) = match denominator {
+            Some(denominator) => {
+                let (is_positive, denominator) = split_literal_sign(denominator);
+                (is_positive, Some(PowerTerm::parse(denominator)?))
+            }
+            None => (true, None),
+        };
+
+        Some(RationalLiteral {
+            is_positive: is_numerator_positive == is_denominator_positive,
+            numerator: PowerTerm::parse(numerator)?,
+            denominator,
+        })
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.is_positive
+    }
+
+    fn terms(&self) -> impl Iterator<Item = &PowerTerm> {
+        std::iter::once(&self.numerator).chain(self.denominator.as_ref())
+    }
+
+    /// Whether the literal has no finite value, e.g. `1/0` or `0^-1`
+    pub fn is_division_by_zero(&self) -> bool {
+        self.terms().any(PowerTerm::is_infinite)
+            || self.denominator.as_ref().is_some_and(PowerTerm::is_zero)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        !self.is_division_by_zero() && self.numerator.is_zero()
+    }
+
+    /// Approximate `log2` of the magnitude of a literal that is neither zero nor a division
+    /// by zero. The estimate is computed in `f64`, so its error grows with the magnitude.
+    pub fn log2(&self) -> f64 {
+        self.numerator.log2() - self.denominator.as_ref().map_or(0.0, PowerTerm::log2)
+    }
+
+    /// Exact `numerator / denominator` of a literal that is neither zero nor a division by
+    /// zero. Fails with `OutOfRange` for terms too large to evaluate.
+    pub fn to_ratio(&self) -> Result<(BigUint, BigUint), Ieee754Error> {
+        let (numerator, denominator) = self.numerator.to_ratio()?;
+        Ok(match &self.denominator {
+            Some(term) => {
+                let (term_numerator, term_denominator) = term.to_ratio()?;
+                (
+                    &numerator * &term_denominator,
+                    &denominator * &term_numerator,
+                )
+            }
+            None => (numerator, denominator),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rational() {
+        let ratio = |text: &str| {
+            let literal = RationalLiteral::parse(text).unwrap();
+            let (numerator, denominator) = literal.to_ratio().unwrap();
+            (
+                literal.is_positive(),
+                numerator.to_string(),
+                denominator.to_string(),
+            )
+        };
+
+        assert_eq!(ratio("1/3"), (true, "1".to_string(), "3".to_string()));
+        assert_eq!(ratio("-22/7"), (false, "22".to_string(), "7".to_string()));
+        assert_eq!(ratio("22/-7"), (false, "22".to_string(), "7".to_string()));
+        assert_eq!(ratio("-1/-2"), (true, "1".to_string(), "2".to_string()));
+        assert_eq!(ratio("2^-3"), (true, "1".to_string(), "8".to_string()));
+        assert_eq!(ratio("-2^2"), (false, "4".to_string(), "1".to_string()));
+        assert_eq!(
+            ratio("0.5^2/3"),
+            (true, "25".to_string(), "300".to_string())
+        );
+        assert_eq!(
+            ratio("1.5e1/10^-1"),
+            (true, "150".to_string(), "1".to_string())
+        );
+        assert_eq!(ratio("7^0"), (true, "1".to_string(), "1".to_string()));
+
+        for invalid in [
+            "", "/", "1/", "/2", "1/2/3", "2^", "2^1.5", "(-2)^2", "2^^2", "2^+-1", "1//2",
+        ] {
+            assert_eq!(RationalLiteral::parse(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_rational_edge_cases() {
+        let parse = |text: &str| RationalLiteral::parse(text).unwrap();
+
+        assert!(parse("1/0").is_division_by_zero());
+        assert!(parse("0^-1").is_division_by_zero());
+        assert!(parse("1/0^2").is_division_by_zero());
+        assert!(!parse("0^0/1").is_division_by_zero());
+        assert!(parse("0/5").is_zero());
+        assert!(parse("-0^3").is_zero());
+        assert!(!parse("0^0").is_zero());
+
+        assert_eq!(parse("2^-126").log2(), -126.0);
+        assert!((parse("1/3").log2() + 3f64.log2()).abs() < 1e-12);
+        assert!((parse("10^-99999999999").log2() + 99999999999.0 * 10f64.log2()).abs() < 1.0);
+
+        assert!(matches!(
+            parse("10^99999999999").to_ratio(),
+            Err(Ieee754Error::OutOfRange(_))
+        ));
+    }
+}
@@ -0,0 +1,392 @@
+use crate::error::Ieee754Error;
+use crate::ieee754_ops::{
+    FloatLayout, SpecialValuePolicy, BFLOAT16_LAYOUT, FLOAT128_LAYOUT, FLOAT16_LAYOUT,
+    FLOAT256_LAYOUT, FLOAT32_LAYOUT, FLOAT64_LAYOUT, FLOAT80_LAYOUT, FP4_E2M1_LAYOUT,
+    FP6_E2M3_LAYOUT, FP6_E3M2_LAYOUT, FP8_E4M3FNUZ_LAYOUT, FP8_E4M3FN_LAYOUT, FP8_E4M3_LAYOUT,
+    FP8_E5M2FNUZ_LAYOUT, FP8_E5M2_LAYOUT, FP8_E8M0_LAYOUT, TENSOR_FLOAT32_LAYOUT,
+    TI_EXTENDED_LAYOUT, TI_SHORT_LAYOUT, TI_SINGLE_LAYOUT,
+};
+use std::cell::RefCell;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Who defined a layout
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LayoutOrigin {
+    /// IEEE 754 and formats that follow its rules
+    Ieee,
+    /// Open Compute Project, the OFP8 and Microscaling (MX) specifications
+    Ocp,
+    Nvidia,
+    Google,
+    Intel,
+    Graphcore,
+    /// The TMS320C3x/C4x DSP formats
+    TexasInstruments,
+    /// Registered at runtime with `register_layout`
+    Custom,
+}
+
+/// Layout known by name, as listed by `list_layouts`
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutInfo {
+    /// Lowercase, the first one is the name, the others are aliases
+    names: Vec<String>,
+    description: String,
+    origin: LayoutOrigin,
+    layout: FloatLayout,
+}
+
+#[wasm_bindgen]
+impl LayoutInfo {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.names[0].clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn aliases(&self) -> Vec<String> {
+        self.names[1..].to_vec()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn origin(&self) -> LayoutOrigin {
+        self.origin
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn special_values(&self) -> SpecialValuePolicy {
+        self.layout.get_special_values()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn layout(&self) -> FloatLayout {
+        self.layout
+    }
+}
+
+impl LayoutInfo {
+    fn has_name(&self, name: &str) -> bool {
+        self.names.iter().any(|known| known == name)
+    }
+}
+
+struct BuiltinLayout {
+    names: &'static [&'static str],
+    description: &'static str,
+    origin: LayoutOrigin,
+    layout: FloatLayout,
+}
+
+impl BuiltinLayout {
+    fn to_info(&self) -> LayoutInfo {
+        LayoutInfo {
+            names: self.names.iter().map(|name| name.to_string()).collect(),
+            description: self.description.to_string(),
+            origin: self.origin,
+            layout: self.layout,
+        }
+    }
+}
+
+/// Layouts every session knows, in the order a layout selector lists them
+const BUILTIN_LAYOUTS: [BuiltinLayout; 20] = [
+    BuiltinLayout {
+        names: &["fp16", "float16", "binary16", "half"],
+        description: "IEEE 754 binary16, half precision",
+        origin: LayoutOrigin::Ieee,
+        layout: FLOAT16_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp32", "float32", "binary32", "single"],
+        description: "IEEE 754 binary32, single precision",
+        origin: LayoutOrigin::Ieee,
+        layout: FLOAT32_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp64", "float64", "binary64", "double"],
+        description: "IEEE 754 binary64, double precision",
+        origin: LayoutOrigin::Ieee,
+        layout: FLOAT64_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp128", "float128", "binary128", "quad"],
+        description: "IEEE 754 binary128, quadruple precision",
+        origin: LayoutOrigin::Ieee,
+        layout: FLOAT128_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp256", "float256", "binary256", "octuple"],
+        description: "IEEE 754 binary256, octuple precision",
+        origin: LayoutOrigin::Ieee,
+        layout: FLOAT256_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp80", "float80", "x87", "extended"],
+        description: "x87 extended precision with an explicit integer bit",
+        origin: LayoutOrigin::Intel,
+        layout: FLOAT80_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["bf16", "bfloat16"],
+        description: "bfloat16, the upper half of binary32",
+        origin: LayoutOrigin::Google,
+        layout: BFLOAT16_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["tf32", "tensorfloat32"],
+        description: "TensorFloat-32, the range of binary32 with the precision of binary16",
+        origin: LayoutOrigin::Nvidia,
+        layout: TENSOR_FLOAT32_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp8-e4m3", "e4m3"],
+        description: "8-bit E4M3 with IEEE 754 infinities and NaNs",
+        origin: LayoutOrigin::Ieee,
+        layout: FP8_E4M3_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp8-e4m3fn", "e4m3fn"],
+        description: "OFP8 E4M3, no infinities and a single NaN, up to 448",
+        origin: LayoutOrigin::Ocp,
+        layout: FP8_E4M3FN_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp8-e5m2", "e5m2"],
+        description: "OFP8 E5M2, IEEE 754 infinities and NaNs",
+        origin: LayoutOrigin::Ocp,
+        layout: FP8_E5M2_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp8-e4m3fnuz", "e4m3fnuz"],
+        description: "8-bit E4M3 with bias 8, NaN in place of negative zero",
+        origin: LayoutOrigin::Graphcore,
+        layout: FP8_E4M3FNUZ_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp8-e5m2fnuz", "e5m2fnuz"],
+        description: "8-bit E5M2 with bias 16, NaN in place of negative zero",
+        origin: LayoutOrigin::Graphcore,
+        layout: FP8_E5M2FNUZ_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp8-e8m0", "e8m0", "ue8m0"],
+        description: "MX scale E8M0, unsigned powers of two from 2^-127 to 2^127",
+        origin: LayoutOrigin::Ocp,
+        layout: FP8_E8M0_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp6-e3m2", "e3m2f", "mxfp6-e3m2"],
+        description: "MX FP6 E3M2, finite only, up to 28",
+        origin: LayoutOrigin::Ocp,
+        layout: FP6_E3M2_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp6-e2m3", "e2m3f", "mxfp6-e2m3"],
+        description: "MX FP6 E2M3, finite only, up to 7.5",
+        origin: LayoutOrigin::Ocp,
+        layout: FP6_E2M3_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["fp4-e2m1", "e2m1f", "mxfp4"],
+        description: "MX FP4 E2M1, finite only, up to 6",
+        origin: LayoutOrigin::Ocp,
+        layout: FP4_E2M1_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["ti16", "ti-short"],
+        description: "TI C3x/C4x short float, two's-complement exponent and significand",
+        origin: LayoutOrigin::TexasInstruments,
+        layout: TI_SHORT_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["ti32", "ti-single"],
+        description: "TI C3x/C4x single precision, from -2^128 to just below 2^128",
+        origin: LayoutOrigin::TexasInstruments,
+        layout: TI_SINGLE_LAYOUT,
+    },
+    BuiltinLayout {
+        names: &["ti40", "ti-extended"],
+        description: "TI C3x/C4x 40-bit extended precision",
+        origin: LayoutOrigin::TexasInstruments,
+        layout: TI_EXTENDED_LAYOUT,
+    },
+];
+
+thread_local! {
+    // wasm runs on a single thread, so this is the registry of the whole page
+    static CUSTOM_LAYOUTS: RefCell<Vec<LayoutInfo>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Built-in layouts followed by the ones registered so far
+#[wasm_bindgen]
+pub fn list_layouts() -> Vec<LayoutInfo> {
+    let mut layouts: Vec<LayoutInfo> = BUILTIN_LAYOUTS.iter().map(BuiltinLayout::to_info).collect();
+    CUSTOM_LAYOUTS.with(|custom| layouts.extend(custom.borrow().iter().cloned()));
+    layouts
+}
+
+/// Layout with `name` as its name or one of its aliases, ignoring case
+#[wasm_bindgen]
+pub fn find_layout(name: &str) -> Option<LayoutInfo> {
+    let name = name.trim().to_lowercase();
+
+    if let Some(builtin) = BUILTIN_LAYOUTS
+        .iter()
+        .find(|builtin| builtin.names.contains(&name.as_str()))
+    {
+        return Some(builtin.to_info());
+    }
+
+    CUSTOM_LAYOUTS.with(|custom| {
+        custom
+            .borrow()
+            .iter()
+            .find(|info| info.has_name(&name))
+            .cloned()
+    })
+}
+
+/// Name of a built-in layout. Registered layouts have no say here, so that the text of a
+/// layout is the same in every session.
+pub fn builtin_name(layout: &FloatLayout) -> Option<&'static str> {
+    BUILTIN_LAYOUTS
+        .iter()
+        .find(|builtin| builtin.layout == *layout)
+        .map(|builtin| builtin.names[0])
+}
+
+/// Makes `layout` available under `name` and `aliases` to `list_layouts` and
+/// `FloatLayout::parse`. Names are case-insensitive and must neither be taken nor read as
+/// an explicit descriptor like `e5m10`.
+#[wasm_bindgen]
+pub fn register_layout(
+    name: &str,
+    aliases: Vec<String>,
+    description: &str,
+    layout: &FloatLayout,
+) -> Result<LayoutInfo, Ieee754Error> {
+    layout.validate()?;
+
+    let mut names: Vec<String> = Vec::with_capacity(aliases.len() + 1);
+    for candidate in std::iter::once(name).chain(aliases.iter().map(String::as_str)) {
+        let candidate = candidate.trim().to_lowercase();
+
+        if candidate.is_empty() || candidate.contains(char::is_whitespace) {
+            return Err(Ieee754Error::InvalidSyntax {
+                input: candidate,
+                expected: "a layout name without whitespace",
+            });
+        }
+        if names.contains(&candidate)
+            || find_layout(&candidate).is_some()
+            || FloatLayout::parse_explicit_descriptor(&candidate).is_some()
+        {
+            return Err(Ieee754Error::LayoutMismatch(format!(
+                "the name {} is already taken",
+                candidate
+            )));
+        }
+
+        names.push(candidate);
+    }
+
+    let info = LayoutInfo {
+        names,
+        description: description.to_string(),
+        origin: LayoutOrigin::Custom,
+        layout: *layout,
+    };
+    CUSTOM_LAYOUTS.with(|custom| custom.borrow_mut().push(info.clone()));
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_layouts() {
+        let layouts = list_layouts();
+        assert_eq!(layouts.len(), BUILTIN_LAYOUTS.len());
+        assert_eq!(layouts[0].name(), "fp16");
+        assert_eq!(layouts[0].aliases(), ["float16", "binary16", "half"]);
+
+        // Every layout has a single entry and reads back from each of its names
+        for info in &layouts {
+            assert_eq!(
+                layouts
+                    .iter()
+                    .filter(|other| other.layout() == info.layout())
+                    .count(),
+                1
+            );
+            for name in std::iter::once(info.name()).chain(info.aliases()) {
+                assert_eq!(find_layout(&name.to_uppercase()).as_ref(), Some(info));
+                assert_eq!(name.parse::<FloatLayout>().unwrap(), info.layout());
+            }
+            assert_eq!(builtin_name(&info.layout()), Some(info.names[0].as_str()));
+            assert_eq!(info.layout().validate(), Ok(()));
+        }
+
+        let e4m3fn = find_layout("e4m3fn").unwrap();
+        assert_eq!(e4m3fn.origin(), LayoutOrigin::Ocp);
+        assert_eq!(e4m3fn.special_values(), SpecialValuePolicy::FiniteWithNan);
+        assert_eq!(find_layout("bf16").unwrap().origin(), LayoutOrigin::Google);
+        assert_eq!(find_layout("tf32").unwrap().origin(), LayoutOrigin::Nvidia);
+        assert_eq!(find_layout("mxfp4").unwrap().origin(), LayoutOrigin::Ocp);
+        assert_eq!(
+            find_layout("ti-single").unwrap().origin(),
+            LayoutOrigin::TexasInstruments
+        );
+        assert!(find_layout("fp42").is_none());
+    }
+
+    #[test]
+    fn test_register_layout() {
+        let layout = FloatLayout::new(1, 6, 9, 31);
+        let info = register_layout(
+            "DLFloat",
+            vec!["dlfloat16".to_string()],
+            "IBM DLFloat, 16 bits",
+            &layout,
+        )
+        .unwrap();
+        assert_eq!(info.name(), "dlfloat");
+        assert_eq!(info.origin(), LayoutOrigin::Custom);
+
+        assert_eq!(list_layouts().last(), Some(&info));
+        assert_eq!(find_layout("DLFLOAT16"), Some(info));
+        assert_eq!("dlfloat".parse::<FloatLayout>().unwrap(), layout);
+        // Text stays the explicit descriptor, which every session reads
+        assert_eq!(layout.to_string(), "e6m9");
+        assert_eq!(builtin_name(&layout), None);
+
+        for (name, aliases) in [
+            ("dlfloat", vec![]),
+            ("mine", vec!["half".to_string()]),
+            ("e5m10", vec![]),
+            ("mine", vec!["mine".to_string()]),
+        ] {
+            assert!(matches!(
+                register_layout(name, aliases, "", &layout),
+                Err(Ieee754Error::LayoutMismatch(_))
+            ));
+        }
+        assert!(matches!(
+            register_layout("my float", vec![], "", &layout),
+            Err(Ieee754Error::InvalidSyntax { .. })
+        ));
+        assert!(matches!(
+            register_layout("tiny", vec![], "", &FloatLayout::new(1, 1, 3, 0)),
+            Err(Ieee754Error::InvalidLayout(_))
+        ));
+        assert!(find_layout("mine").is_none());
+    }
+}
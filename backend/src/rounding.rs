@@ -0,0 +1,304 @@
+use crate::bigint::BigUint;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// IEEE 754 rounding-direction attributes plus round-to-odd and stochastic rounding
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// roundTiesToEven, the IEEE default
+    #[default]
+    TiesToEven,
+    /// roundTiesToAway
+    TiesToAway,
+    /// roundTowardPositive
+    TowardPositive,
+    /// roundTowardNegative
+    TowardNegative,
+    /// roundTowardZero
+    TowardZero,
+    /// Round to odd: truncate and set the last kept bit when anything was dropped. Not an
+    /// IEEE attribute, but an intermediate result rounded to odd with at least two extra
+    /// bits rounds correctly into the final precision later.
+    ToOdd,
+    /// Round up with a probability equal to the dropped fraction of a unit in the last
+    /// place, so that the rounded result is the exact value on average. The random draw
+    /// comes from `seed` mixed with the dropped bits, so one seed rounds different values
+    /// independently while the same seed and value always round the same way.
+    Stochastic { seed: u64 },
+}
+
+/// The rounding modes that JS can pass directly, as enums exported through wasm_bindgen
+/// cannot carry data. Stochastic rounding takes its seed as a separate argument there.
+#[wasm_bindgen(js_name = RoundingMode)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum RoundingDirection {
+    #[default]
+    TiesToEven,
+    TiesToAway,
+    TowardPositive,
+    TowardNegative,
+    TowardZero,
+    ToOdd,
+}
+
+impl From<RoundingDirection> for RoundingMode {
+    fn from(direction: RoundingDirection) -> Self {
+        match direction {
+            RoundingDirection::TiesToEven => RoundingMode::TiesToEven,
+            RoundingDirection::TiesToAway => RoundingMode::TiesToAway,
+            RoundingDirection::TowardPositive => RoundingMode::TowardPositive,
+            RoundingDirection::TowardNegative => RoundingMode::TowardNegative,
+            RoundingDirection::TowardZero => RoundingMode::TowardZero,
+            RoundingDirection::ToOdd => RoundingMode::ToOdd,
+        }
+    }
+}
+
+impl RoundingMode {
+    /// Whether a magnitude truncated to the target precision has to be incremented by one
+    /// unit in the last place.
+    ///
+    /// `lowest_bit` is the last kept bit and `remainder / divisor` the dropped fraction of
+    /// a unit in the last place, less than one.
+    pub fn should_increment(
+        self,
+        is_positive: bool,
+        lowest_bit: bool,
+        remainder: &BigUint,
+        divisor: &BigUint,
+    ) -> bool {
+        // Guard bit is the first dropped bit, sticky is everything below it
+        let double_remainder = remainder.mul_small(2);
+        let guard = double_remainder >= *divisor;
+        let sticky = if guard {
+            double_remainder > *divisor
+        } else {
+            !remainder.is_zero()
+        };
+        let is_inexact = guard || sticky;
+
+        match self {
+            RoundingMode::TiesToEven => guard && (sticky || lowest_bit),
+            RoundingMode::TiesToAway => guard,
+            RoundingMode::TowardPositive => is_inexact && is_positive,
+            RoundingMode::TowardNegative => is_inexact && !is_positive,
+            RoundingMode::TowardZero => false,
+            RoundingMode::ToOdd => is_inexact && !lowest_bit,
+            RoundingMode::Stochastic { seed } => {
+                // The first 64 dropped bits pick the stream, still a bijection of the seed
+                let dropped = (remainder << 64).divrem(divisor).0.low_u64();
+                let stream = SplitMix64::new(dropped).next_u64();
+                // draw / 2^64 < remainder / divisor
+                let draw = BigUint::from_u64(SplitMix64::new(seed ^ stream).next_u64());
+                &draw * divisor < remainder << 64
+            }
+        }
+    }
+
+    /// Whether a magnitude beyond the largest finite number rounds to infinity rather than
+    /// to the largest finite number of the same sign
+    pub fn overflows_to_infinity(self, is_positive: bool) -> bool {
+        match self {
+            RoundingMode::TiesToEven | RoundingMode::TiesToAway => true,
+            RoundingMode::TowardPositive => is_positive,
+            RoundingMode::TowardNegative => !is_positive,
+            RoundingMode::TowardZero | RoundingMode::ToOdd => false,
+            // Only the carry out of the largest finite number is random, anything larger
+            // is past its upper neighbour already
+            RoundingMode::Stochastic { .. } => true,
+        }
+    }
+}
+
+/// How many of the 2^64 possible stochastic draws round a dropped fraction of
+/// `remainder / divisor` up, i.e. the probability of rounding up times 2^64
+pub fn stochastic_round_up_weight(remainder: &BigUint, divisor: &BigUint) -> BigUint {
+    // Draws below remainder * 2^64 / divisor round up
+    let (quotient, rest) = (remainder << 64).divrem(divisor);
+    if rest.is_zero() {
+        quotient
+    } else {
+        &quotient + &BigUint::one()
+    }
+}
+
+/// SplitMix64 generator (Steele, Lea and Flood), small and well mixed enough to seed
+/// reproducible stochastic rounding
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// What a conversion produces for magnitudes beyond the largest finite number
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Infinity or the largest finite number, as the rounding mode dictates
+    #[default]
+    Ieee,
+    /// Always the largest finite number of the same sign, infinite inputs included
+    /// ("satfinite" in CUDA and the OCP FP8 specification)
+    SatFinite,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_increment() {
+        use RoundingMode::*;
+
+        // (lowest_bit, dropped fraction) for: below half, exact tie on even, exact tie on
+        // odd, above half
+        let below_half = (false, 1, 4);
+        let tie_even = (false, 2, 4);
+        let tie_odd = (true, 2, 4);
+        let above_half = (false, 3, 4);
+
+        let cases = [
+            (TiesToEven, true, [false, false, true, true]),
+            (TiesToAway, true, [false, true, true, true]),
+            (TowardPositive, true, [true, true, true, true]),
+            (TowardPositive, false, [false, false, false, false]),
+            (TowardNegative, true, [false, false, false, false]),
+            (TowardNegative, false, [true, true, true, true]),
+            (TowardZero, true, [false, false, false, false]),
+            (ToOdd, true, [true, true, false, true]),
+            (ToOdd, false, [true, true, false, true]),
+        ];
+
+        for (mode, is_positive, expected) in cases {
+            for ((lowest_bit, remainder, divisor), expected) in
+                [below_half, tie_even, tie_odd, above_half]
+                    .into_iter()
+                    .zip(expected)
+            {
+                assert_eq!(
+                    mode.should_increment(
+                        is_positive,
+                        lowest_bit,
+                        &BigUint::from_u64(remainder),
+                        &BigUint::from_u64(divisor)
+                    ),
+                    expected,
+                    "{:?} {} {} {}/{}",
+                    mode,
+                    is_positive,
+                    lowest_bit,
+                    remainder,
+                    divisor
+                );
+            }
+        }
+
+        // Exact values are never changed
+        let (zero, one) = (BigUint::zero(), BigUint::one());
+        for mode in [
+            TiesToEven,
+            TiesToAway,
+            TowardPositive,
+            TowardNegative,
+            TowardZero,
+            ToOdd,
+            Stochastic { seed: 0 },
+            Stochastic { seed: u64::MAX },
+        ] {
+            assert!(!mode.should_increment(true, true, &zero, &one));
+            assert!(!mode.should_increment(false, true, &zero, &one));
+        }
+    }
+
+    #[test]
+    fn test_stochastic_rounding() {
+        use RoundingMode::*;
+
+        // Reference outputs of SplitMix64 seeded with 0
+        let mut generator = SplitMix64::new(0);
+        assert_eq!(generator.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(generator.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+
+        // The share of seeds that round up follows the dropped fraction
+        let divisor = BigUint::from_u64(8);
+        for remainder in 0..8 {
+            let remainder = BigUint::from_u64(remainder);
+            let up = (0..4000)
+                .filter(|&seed| {
+                    Stochastic { seed }.should_increment(true, false, &remainder, &divisor)
+                })
+                .count();
+            let expected = 4000.0 * remainder.to_f64() / 8.0;
+            assert!((up as f64 - expected).abs() < 150.0, "{} {}", remainder, up);
+        }
+
+        // One seed rounds different values independently, not all below one threshold
+        let divisor = BigUint::power_of_two(16);
+        let (mut up, mut inversions) = (0, 0);
+        let mut previous = false;
+        for remainder in 1..4096u64 {
+            let increment = Stochastic { seed: 42 }.should_increment(
+                true,
+                false,
+                &BigUint::from_u64(remainder * 16),
+                &divisor,
+            );
+            up += increment as usize;
+            inversions += (previous && !increment) as usize;
+            previous = increment;
+        }
+        // Still half of them on average, but not only the ones with the largest fractions
+        assert!((up as f64 - 4095.0 / 2.0).abs() < 150.0, "{}", up);
+        assert!(inversions > 500, "{}", inversions);
+
+        // Same seed, same decision
+        let (remainder, divisor) = (BigUint::from_u64(1), BigUint::from_u64(3));
+        let mode = Stochastic { seed: 42 };
+        assert_eq!(
+            mode.should_increment(true, false, &remainder, &divisor),
+            mode.should_increment(true, false, &remainder, &divisor)
+        );
+
+        assert_eq!(
+            stochastic_round_up_weight(&BigUint::from_u64(1), &BigUint::from_u64(4)),
+            BigUint::power_of_two(62)
+        );
+        assert_eq!(
+            stochastic_round_up_weight(&BigUint::from_u64(1), &BigUint::from_u64(3)).to_string(),
+            "6148914691236517206"
+        );
+        assert!(stochastic_round_up_weight(&BigUint::zero(), &BigUint::one()).is_zero());
+    }
+
+    #[test]
+    fn test_overflows_to_infinity() {
+        use RoundingMode::*;
+
+        let cases = [
+            (TiesToEven, [true, true]),
+            (TiesToAway, [true, true]),
+            (TowardPositive, [true, false]),
+            (TowardNegative, [false, true]),
+            (TowardZero, [false, false]),
+            (ToOdd, [false, false]),
+            (Stochastic { seed: 7 }, [true, true]),
+        ];
+
+        for (mode, [positive, negative]) in cases {
+            assert_eq!(mode.overflows_to_infinity(true), positive, "{:?}", mode);
+            assert_eq!(mode.overflows_to_infinity(false), negative, "{:?}", mode);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! `FloatLayout` serializes to an object with all of its fields and `BitField` to its bit
+//! string. An `EncodedValue` is an object with the two, e.g. for the JSON format
+//!
+//! ```json
+//! {
+//!   "layout": {
+//!     "sign": 1,
+//!     "exponent": 8,
+//!     "mantissa": 23,
+//!     "exponent_bias": 127,
+//!     "explicit_integer_bit": false,
+//!     "special_values": "ieee",
+//!     "field_order": "sign_exponent_mantissa",
+//!     "exponent_encoding": "biased",
+//!     "mantissa_encoding": "sign_magnitude"
+//!   },
+//!   "bits": "00111111100000000000000000000000"
+//! }
+//! ```
+//!
+//! Deserialization also accepts the compact strings of `Display`, such as `"e5m2"` for a
+//! layout or `"fp32:0x3f800000"` for an encoding, and layout objects may leave out the
+//! fields that have defaults in descriptors. Fields are serialized as compact strings with
+//! `#[serde(with = "ieee754_web::serialization::compact")]`. Everything read is validated
+//! like the input of the conversion functions.
+
+use crate::bitfield::BitField;
+use crate::encoded::EncodedValue;
+use crate::ieee754_ops::{
+    ExponentEncoding, FieldOrder, FloatLayout, MantissaEncoding, SpecialValuePolicy,
+};
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// `serialize_with` and `deserialize_with` functions that write the `Display` text of a
+/// value and read it back with `FromStr`
+pub mod compact {
+    use super::*;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Reads either the compact string of a `T` or an object that `Fields` turns into one
+struct StringOrFields<T, Fields> {
+    expecting: &'static str,
+    marker: PhantomData<(T, Fields)>,
+}
+
+impl<T, Fields> StringOrFields<T, Fields> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, Fields> Visitor<'de> for StringOrFields<T, Fields>
+where
+    T: FromStr<Err = crate::error::Ieee754Error> + TryFrom<Fields, Error = T::Err>,
+    Fields: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<T, E> {
+        text.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+        let fields = Fields::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        T::try_from(fields).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for FloatLayout {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fields = serializer.serialize_struct("FloatLayout", 9)?;
+        fields.serialize_field("sign", &self.get_sign_size())?;
+        fields.serialize_field("exponent", &self.get_exponent_size())?;
+        fields.serialize_field("mantissa", &self.get_mantissa_size())?;
+        fields.serialize_field("exponent_bias", &self.get_exponent_bias())?;
+        fields.serialize_field("explicit_integer_bit", &self.has_explicit_integer_bit())?;
+        fields.serialize_field("special_values", &self.get_special_values())?;
+        fields.serialize_field("field_order", &self.get_field_order())?;
+        fields.serialize_field("exponent_encoding", &self.get_exponent_encoding())?;
+        fields.serialize_field("mantissa_encoding", &self.get_mantissa_encoding())?;
+        fields.end()
+    }
+}
+
+/// Object form of a `FloatLayout`, defaulting like `parse_explicit_descriptor`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayoutFields {
+    #[serde(default = "one_sign_bit")]
+    sign: u8,
+    exponent: u8,
+    mantissa: u8,
+    #[serde(default)]
+    exponent_bias: Option<u32>,
+    #[serde(default)]
+    explicit_integer_bit: bool,
+    #[serde(default)]
+    special_values: SpecialValuePolicy,
+    #[serde(default)]
+    field_order: FieldOrder,
+    #[serde(default)]
+    exponent_encoding: ExponentEncoding,
+    #[serde(default)]
+    mantissa_encoding: MantissaEncoding,
+}
+
+const fn one_sign_bit() -> u8 {
+    1
+}
+
+impl TryFrom<LayoutFields> for FloatLayout {
+    type Error = crate::error::Ieee754Error;
+
+    fn try_from(fields: LayoutFields) -> Result<Self, Self::Error> {
+        let layout = FloatLayout::new(fields.sign, fields.exponent, fields.mantissa, 0)
+            .with_explicit_integer_bit(fields.explicit_integer_bit)
+            .with_special_values(fields.special_values)
+            .with_field_order(fields.field_order)
+            .with_exponent_encoding(fields.exponent_encoding)
+            .with_mantissa_encoding(fields.mantissa_encoding);
+        let layout =
+            layout.with_exponent_bias(fields.exponent_bias.unwrap_or(layout.implied_bias()));
+        layout.validate()?;
+        Ok(layout)
+    }
+}
+
+impl<'de> Deserialize<'de> for FloatLayout {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(StringOrFields::<FloatLayout, LayoutFields>::new(
+            "a layout descriptor or an object with the layout fields",
+        ))
+    }
+}
+
+impl Serialize for BitField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BitField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        BitField::parse(&text).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for EncodedValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fields = serializer.serialize_struct("EncodedValue", 2)?;
+        fields.serialize_field("layout", &self.layout())?;
+        fields.serialize_field("bits", &self.get_bits())?;
+        fields.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EncodedValueFields {
+    layout: FloatLayout,
+    bits: BitField,
+}
+
+impl TryFrom<EncodedValueFields> for EncodedValue {
+    type Error = crate::error::Ieee754Error;
+
+    fn try_from(fields: EncodedValueFields) -> Result<Self, Self::Error> {
+        EncodedValue::from_bits(&fields.layout, fields.bits)
+    }
+}
+
+impl<'de> Deserialize<'de> for EncodedValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(StringOrFields::<EncodedValue, EncodedValueFields>::new(
+            "an encoding such as fp32:0x3f800000 or an object with a layout and bits",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754_ops::{
+        FLOAT32_LAYOUT, FLOAT80_LAYOUT, FP8_E4M3FNUZ_LAYOUT, TI_SINGLE_LAYOUT,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_layout_json() {
+        assert_eq!(
+            serde_json::to_value(FLOAT80_LAYOUT).unwrap(),
+            json!({
+                "sign": 1,
+                "exponent": 15,
+                "mantissa": 64,
+                "exponent_bias": 16383,
+                "explicit_integer_bit": true,
+                "special_values": "ieee",
+                "field_order": "sign_exponent_mantissa",
+                "exponent_encoding": "biased",
+                "mantissa_encoding": "sign_magnitude"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(FP8_E4M3FNUZ_LAYOUT).unwrap()["special_values"],
+            "nan_is_negative_zero"
+        );
+
+        for layout in [
+            FLOAT32_LAYOUT,
+            FLOAT80_LAYOUT,
+            FP8_E4M3FNUZ_LAYOUT,
+            TI_SINGLE_LAYOUT,
+        ] {
+            let json = serde_json::to_string(&layout).unwrap();
+            assert_eq!(serde_json::from_str::<FloatLayout>(&json).unwrap(), layout);
+        }
+
+        // Compact strings and objects with defaults
+        assert_eq!(
+            serde_json::from_str::<FloatLayout>("\"binary32\"").unwrap(),
+            FLOAT32_LAYOUT
+        );
+        assert_eq!(
+            serde_json::from_value::<FloatLayout>(json!({"exponent": 8, "mantissa": 23})).unwrap(),
+            FLOAT32_LAYOUT
+        );
+        assert_eq!(
+            serde_json::from_value::<FloatLayout>(json!({
+                "exponent": 4,
+                "mantissa": 3,
+                "exponent_bias": 8,
+                "special_values": "nan_is_negative_zero"
+            }))
+            .unwrap(),
+            FP8_E4M3FNUZ_LAYOUT
+        );
+        // A two's-complement exponent implies its bias
+        assert_eq!(
+            serde_json::from_value::<FloatLayout>(json!({
+                "exponent": 8,
+                "mantissa": 23,
+                "special_values": "finite_only",
+                "field_order": "exponent_sign_mantissa",
+                "exponent_encoding": "twos_complement",
+                "mantissa_encoding": "twos_complement"
+            }))
+            .unwrap(),
+            TI_SINGLE_LAYOUT
+        );
+
+        // Validated like any other input
+        let error = serde_json::from_value::<FloatLayout>(json!({"exponent": 8, "mantissa": 1}))
+            .unwrap_err();
+        assert!(error.to_string().contains("invalid layout"), "{}", error);
+        assert!(serde_json::from_value::<FloatLayout>(json!("fp42")).is_err());
+        assert!(serde_json::from_value::<FloatLayout>(json!({
+            "exponent": 8,
+            "mantissa": 23,
+            "radix": 2
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_encoded_value_json() {
+        let one = EncodedValue::new("00111111100000000000000000000000", &FLOAT32_LAYOUT).unwrap();
+        let json = serde_json::to_value(one).unwrap();
+        assert_eq!(json["bits"], "00111111100000000000000000000000");
+        assert_eq!(json["layout"]["mantissa"], 23);
+        assert_eq!(serde_json::from_value::<EncodedValue>(json).unwrap(), one);
+
+        assert_eq!(
+            serde_json::from_value::<EncodedValue>(json!("fp32:0x3f800000")).unwrap(),
+            one
+        );
+        assert_eq!(
+            serde_json::from_value::<EncodedValue>(json!({
+                "layout": "fp32",
+                "bits": "00111111100000000000000000000000"
+            }))
+            .unwrap(),
+            one
+        );
+
+        let error = serde_json::from_value::<EncodedValue>(json!({
+            "layout": "fp32",
+            "bits": "0011110000000000"
+        }))
+        .unwrap_err();
+        assert!(error.to_string().contains("wrong width"), "{}", error);
+        assert!(serde_json::from_value::<BitField>(json!("0120")).is_err());
+
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Saved {
+            #[serde(with = "compact")]
+            layout: FloatLayout,
+            #[serde(with = "compact")]
+            value: EncodedValue,
+        }
+        let layout = FloatLayout::new(1, 6, 9, 31);
+        let saved = Saved {
+            layout,
+            value: EncodedValue::new("0011111000000000", &layout).unwrap(),
+        };
+        let json = serde_json::to_value(&saved).unwrap();
+        assert_eq!(json, json!({"layout": "e6m9", "value": "e6m9:0x3e00"}));
+        assert_eq!(serde_json::from_value::<Saved>(json).unwrap(), saved);
+    }
+}
@@ -0,0 +1,434 @@
+use crate::bitfield::BitField;
+use crate::encoded::EncodedValue;
+use crate::error::Ieee754Error;
+use crate::ieee754_ops::{
+    binary_to_decimal_with_mode, binary_to_hex, convert_binary, decimal_to_binary_with_rounding,
+    parse_stored_binary, DecimalMode, FloatLayout,
+};
+use crate::rounding::{RoundingDirection, RoundingMode};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Order of the bytes in a word, or of the words in a value
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    /// Most significant first
+    #[default]
+    BigEndian,
+    /// Least significant first
+    LittleEndian,
+}
+
+/// How the encodings of a layout sit in memory. The value is split into words of
+/// `word_bytes` bytes, the bytes of each word follow `byte_order` and the words
+/// `word_order`, which covers the mixed-endian PDP-11, VAX and ARM FPA formats. Layouts
+/// that do not fill whole bytes are stored in the low bits of the fewest bytes that hold
+/// them, with the padding bits above zero.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StorageLayout {
+    layout: FloatLayout,
+    byte_order: ByteOrder,
+    word_bytes: u8,
+    word_order: ByteOrder,
+}
+
+#[wasm_bindgen]
+impl StorageLayout {
+    /// The whole value as a single word of `byte_order`
+    #[wasm_bindgen(constructor)]
+    pub fn new(layout: &FloatLayout, byte_order: ByteOrder) -> Self {
+        Self {
+            layout: *layout,
+            byte_order,
+            word_bytes: layout.get_size().div_ceil(8) as u8,
+            word_order: ByteOrder::BigEndian,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn big_endian(layout: &FloatLayout) -> StorageLayout {
+        Self::new(layout, ByteOrder::BigEndian)
+    }
+
+    #[wasm_bindgen]
+    pub fn little_endian(layout: &FloatLayout) -> StorageLayout {
+        Self::new(layout, ByteOrder::LittleEndian)
+    }
+
+    /// Little-endian 16-bit words, most significant word first, as the floats of the
+    /// PDP-11 and the VAX
+    #[wasm_bindgen]
+    pub fn pdp_endian(layout: &FloatLayout) -> StorageLayout {
+        Self::little_endian(layout).with_words(2, ByteOrder::BigEndian)
+    }
+
+    /// Little-endian 32-bit words, most significant word first, as the doubles of the ARM
+    /// floating point accelerator (FPA)
+    #[wasm_bindgen]
+    pub fn arm_fpa_endian(layout: &FloatLayout) -> StorageLayout {
+        Self::little_endian(layout).with_words(4, ByteOrder::BigEndian)
+    }
+
+    /// Copy of the storage that splits values into words of `word_bytes` bytes stored in
+    /// `word_order`
+    #[wasm_bindgen]
+    pub fn with_words(&self, word_bytes: u8, word_order: ByteOrder) -> StorageLayout {
+        Self {
+            word_bytes,
+            word_order,
+            ..*self
+        }
+    }
+
+    /// Checks the layout and that the words evenly split the stored bytes
+    #[wasm_bindgen]
+    pub fn validate(&self) -> Result<(), Ieee754Error> {
+        self.layout.validate()?;
+
+        if !self
+            .get_byte_size()
+            .is_multiple_of(self.word_bytes as usize)
+        {
+            return Err(Ieee754Error::InvalidLayout(format!(
+                "{} bytes do not split into words of {} bytes",
+                self.get_byte_size(),
+                self.word_bytes
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn layout(&self) -> FloatLayout {
+        self.layout
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn word_bytes(&self) -> u8 {
+        self.word_bytes
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn word_order(&self) -> ByteOrder {
+        self.word_order
+    }
+
+    /// Bytes taken by one value, including any padding bits
+    #[wasm_bindgen]
+    pub fn get_byte_size(&self) -> usize {
+        self.layout.get_size().div_ceil(8)
+    }
+}
+
+impl StorageLayout {
+    /// Position in big-endian order of the byte stored at `index`
+    fn big_endian_index(&self, index: usize) -> usize {
+        let word_bytes = self.word_bytes as usize;
+        let words = self.get_byte_size() / word_bytes;
+
+        let (word, byte) = (index / word_bytes, index % word_bytes);
+        let word = match self.word_order {
+            ByteOrder::BigEndian => word,
+            ByteOrder::LittleEndian => words - 1 - word,
+        };
+        let byte = match self.byte_order {
+            ByteOrder::BigEndian => byte,
+            ByteOrder::LittleEndian => word_bytes - 1 - byte,
+        };
+
+        word * word_bytes + byte
+    }
+
+    /// The canonical encoding held by `bytes`, which have to be exactly one value
+    pub fn decode(&self, bytes: &[u8]) -> Result<BitField, Ieee754Error> {
+        self.validate()?;
+
+        let byte_size = self.get_byte_size();
+        if bytes.len() != byte_size {
+            return Err(Ieee754Error::WrongWidth {
+                expected: 8 * byte_size,
+                found: 8 * bytes.len(),
+            });
+        }
+
+        let mut padded = BitField::new(8 * byte_size);
+        for (index, byte) in bytes.iter().enumerate() {
+            let low_bit = 8 * (byte_size - 1 - self.big_endian_index(index));
+            for bit in 0..8 {
+                padded.set_bit(low_bit + bit, byte & (1 << bit) != 0);
+            }
+        }
+
+        let size = self.layout.get_size();
+        if !padded.all_bits_in_range_are(size.., false) {
+            return Err(Ieee754Error::OutOfRange(format!(
+                "the padding bits above the {}-bit encoding are not zero",
+                size
+            )));
+        }
+
+        Ok(padded.get_sub(0..size))
+    }
+
+    /// The bytes that store `binary`, an encoding of the layout
+    pub fn encode(&self, binary: BitField) -> Result<Vec<u8>, Ieee754Error> {
+        self.validate()?;
+
+        let size = self.layout.get_size();
+        if binary.size() != size {
+            return Err(Ieee754Error::WrongWidth {
+                expected: size,
+                found: binary.size(),
+            });
+        }
+
+        let byte_size = self.get_byte_size();
+        Ok((0..byte_size)
+            .map(|index| {
+                let low_bit = 8 * (byte_size - 1 - self.big_endian_index(index));
+                (0..8)
+                    .filter(|bit| low_bit + bit < size && binary.get_bit(low_bit + bit))
+                    .fold(0u8, |byte, bit| byte | 1 << bit)
+            })
+            .collect())
+    }
+}
+
+#[wasm_bindgen]
+impl EncodedValue {
+    /// Reads one value stored as `storage` says
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8], storage: &StorageLayout) -> Result<EncodedValue, Ieee754Error> {
+        Self::from_bits(&storage.layout, storage.decode(bytes)?)
+    }
+
+    /// Bytes of the value stored as `storage` says, which has to be for the same layout
+    #[wasm_bindgen]
+    pub fn to_bytes(&self, storage: &StorageLayout) -> Result<Vec<u8>, Ieee754Error> {
+        if storage.layout != self.layout() {
+            return Err(Ieee754Error::LayoutMismatch(format!(
+                "a {} value cannot be stored as {}",
+                self.layout(),
+                storage.layout
+            )));
+        }
+
+        storage.encode(self.get_bits())
+    }
+}
+
+/// Bit string of the value stored in `bytes`, the input of every conversion that takes
+/// an encoding
+#[wasm_bindgen]
+pub fn bytes_to_binary(bytes: &[u8], storage: &StorageLayout) -> Result<String, Ieee754Error> {
+    Ok(storage.decode(bytes)?.to_string())
+}
+
+/// Bytes that store the bit string `binary`, the output of every conversion that makes
+/// an encoding
+#[wasm_bindgen]
+pub fn binary_to_bytes(binary: &str, storage: &StorageLayout) -> Result<Vec<u8>, Ieee754Error> {
+    storage.encode(parse_stored_binary(binary, &storage.layout)?)
+}
+
+pub fn decimal_to_bytes(
+    decimal: &str,
+    storage: &StorageLayout,
+    rounding: RoundingMode,
+) -> Result<Vec<u8>, Ieee754Error> {
+    let binary = decimal_to_binary_with_rounding(decimal, &storage.layout, rounding)?;
+    binary_to_bytes(&binary, storage)
+}
+
+#[wasm_bindgen(js_name = decimal_to_bytes)]
+pub fn js_decimal_to_bytes(
+    decimal: &str,
+    storage: &StorageLayout,
+    rounding: RoundingDirection,
+) -> Result<Vec<u8>, Ieee754Error> {
+    decimal_to_bytes(decimal, storage, rounding.into())
+}
+
+#[wasm_bindgen]
+pub fn bytes_to_decimal(
+    bytes: &[u8],
+    storage: &StorageLayout,
+    mode: DecimalMode,
+    precision: u8,
+) -> Result<String, Ieee754Error> {
+    let binary = bytes_to_binary(bytes, storage)?;
+    binary_to_decimal_with_mode(&binary, &storage.layout, mode, precision)
+}
+
+#[wasm_bindgen]
+pub fn bytes_to_hex(bytes: &[u8], storage: &StorageLayout) -> Result<String, Ieee754Error> {
+    binary_to_hex(&bytes_to_binary(bytes, storage)?, &storage.layout)
+}
+
+/// `convert_binary` between stored values, which also changes byte order when only the
+/// storage differs
+pub fn convert_bytes(
+    bytes: &[u8],
+    from: &StorageLayout,
+    to: &StorageLayout,
+    rounding: RoundingMode,
+) -> Result<Vec<u8>, Ieee754Error> {
+    to.validate()?;
+    let binary = convert_binary(
+        &bytes_to_binary(bytes, from)?,
+        &from.layout,
+        &to.layout,
+        rounding,
+    )?;
+    binary_to_bytes(&binary, to)
+}
+
+#[wasm_bindgen(js_name = convert_bytes)]
+pub fn js_convert_bytes(
+    bytes: &[u8],
+    from: &StorageLayout,
+    to: &StorageLayout,
+    rounding: RoundingDirection,
+) -> Result<Vec<u8>, Ieee754Error> {
+    convert_bytes(bytes, from, to, rounding.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754_ops::{
+        SpecialValuePolicy, FLOAT32_LAYOUT, FLOAT64_LAYOUT, FLOAT80_LAYOUT, FP8_E4M3FN_LAYOUT,
+    };
+
+    #[test]
+    fn test_byte_orders() {
+        let pi = std::f64::consts::PI;
+        let bits = BitField::make_u64(pi.to_bits(), 64);
+        let big = pi.to_be_bytes();
+
+        let storage = StorageLayout::big_endian(&FLOAT64_LAYOUT);
+        assert_eq!(storage.encode(bits).unwrap(), big);
+        assert_eq!(storage.decode(&big).unwrap().to_string(), bits.to_string());
+
+        let storage = StorageLayout::little_endian(&FLOAT64_LAYOUT);
+        assert_eq!(storage.encode(bits).unwrap(), pi.to_le_bytes());
+        assert_eq!(
+            storage.decode(&pi.to_le_bytes()).unwrap().to_string(),
+            bits.to_string()
+        );
+
+        // The words of ARM FPA doubles are in big-endian order, their bytes are not
+        let storage = StorageLayout::arm_fpa_endian(&FLOAT64_LAYOUT);
+        let fpa = [
+            big[3], big[2], big[1], big[0], big[7], big[6], big[5], big[4],
+        ];
+        assert_eq!(storage.encode(bits).unwrap(), fpa);
+        assert_eq!(storage.decode(&fpa).unwrap().to_string(), bits.to_string());
+
+        // 1.0 in PDP-11 order is 80 3f 00 00 in memory
+        let storage = StorageLayout::pdp_endian(&FLOAT32_LAYOUT);
+        assert_eq!(
+            binary_to_bytes("00111111100000000000000000000000", &storage).unwrap(),
+            [0x80, 0x3f, 0x00, 0x00]
+        );
+        assert_eq!(
+            bytes_to_binary(&[0x80, 0x3f, 0x00, 0x00], &storage).unwrap(),
+            "00111111100000000000000000000000"
+        );
+
+        // Little-endian words of little-endian bytes are plain little-endian
+        let storage =
+            StorageLayout::little_endian(&FLOAT80_LAYOUT).with_words(2, ByteOrder::LittleEndian);
+        let value = EncodedValue::parse("fp80:0x3fff8000000000000000").unwrap();
+        assert_eq!(
+            value.to_bytes(&storage).unwrap(),
+            [0, 0, 0, 0, 0, 0, 0, 0x80, 0xff, 0x3f]
+        );
+        assert_eq!(
+            EncodedValue::from_bytes(&[0, 0, 0, 0, 0, 0, 0, 0x80, 0xff, 0x3f], &storage).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_byte_conversions() {
+        let storage = StorageLayout::little_endian(&FLOAT32_LAYOUT);
+        assert_eq!(
+            decimal_to_bytes("0.1", &storage, RoundingMode::TiesToEven).unwrap(),
+            0.1f32.to_le_bytes()
+        );
+        assert_eq!(
+            bytes_to_decimal(&2.5f32.to_le_bytes(), &storage, DecimalMode::Shortest, 0).unwrap(),
+            "2.5"
+        );
+        assert_eq!(
+            bytes_to_hex(&3.0f32.to_le_bytes(), &storage).unwrap(),
+            "0x1.8p+1"
+        );
+
+        let pdp = StorageLayout::pdp_endian(&FLOAT32_LAYOUT);
+        assert_eq!(
+            convert_bytes(
+                &1.0f32.to_le_bytes(),
+                &storage,
+                &pdp,
+                RoundingMode::TiesToEven
+            )
+            .unwrap(),
+            [0x80, 0x3f, 0x00, 0x00]
+        );
+        let doubles = StorageLayout::big_endian(&FLOAT64_LAYOUT);
+        assert_eq!(
+            convert_bytes(
+                &0.1f64.to_be_bytes(),
+                &doubles,
+                &storage,
+                RoundingMode::TiesToEven
+            )
+            .unwrap(),
+            0.1f32.to_le_bytes()
+        );
+
+        // Values that do not fill their bytes keep the padding bits zero
+        let layout =
+            FloatLayout::new(1, 3, 2, 3).with_special_values(SpecialValuePolicy::FiniteOnly);
+        let storage = StorageLayout::little_endian(&layout);
+        assert_eq!(storage.get_byte_size(), 1);
+        assert_eq!(binary_to_bytes("001100", &storage).unwrap(), [0b001100]);
+        assert_eq!(bytes_to_binary(&[0b001100], &storage).unwrap(), "001100");
+        assert_eq!(
+            bytes_to_binary(&[0b1001100], &storage).unwrap_err(),
+            Ieee754Error::OutOfRange(
+                "the padding bits above the 6-bit encoding are not zero".to_string()
+            )
+        );
+
+        assert_eq!(
+            bytes_to_binary(&[0; 3], &pdp).unwrap_err(),
+            Ieee754Error::WrongWidth {
+                expected: 32,
+                found: 24
+            }
+        );
+        assert_eq!(
+            StorageLayout::pdp_endian(&FP8_E4M3FN_LAYOUT)
+                .validate()
+                .unwrap_err(),
+            Ieee754Error::InvalidLayout("1 bytes do not split into words of 2 bytes".to_string())
+        );
+        assert!(matches!(
+            EncodedValue::parse("fp32:0x3f800000")
+                .unwrap()
+                .to_bytes(&StorageLayout::little_endian(&FLOAT64_LAYOUT))
+                .unwrap_err(),
+            Ieee754Error::LayoutMismatch(_)
+        ));
+    }
+}
@@ -1,5 +1,25 @@
 let wasm;
 
+const heap = new Array(128).fill(undefined);
+
+heap.push(undefined, null, true, false);
+
+function getObject(idx) { return heap[idx]; }
+
+let heap_next = heap.length;
+
+function dropObject(idx) {
+    if (idx < 132) return;
+    heap[idx] = heap_next;
+    heap_next = idx;
+}
+
+function takeObject(idx) {
+    const ret = getObject(idx);
+    dropObject(idx);
+    return ret;
+}
+
 const cachedTextDecoder = (typeof TextDecoder !== 'undefined' ? new TextDecoder('utf-8', { ignoreBOM: true, fatal: true }) : { decode: () => { throw Error('TextDecoder not available') } } );
 
 if (typeof TextDecoder !== 'undefined') { cachedTextDecoder.decode(); };
@@ -17,13 +37,14 @@ function getStringFromWasm0(ptr, len) {
     ptr = ptr >>> 0;
     return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
 }
-/**
-* @param {PredefinedLayout} layout_type
-* @returns {FloatLayout}
-*/
-export function get_predefined_layout(layout_type) {
-    const ret = wasm.get_predefined_layout(layout_type);
-    return FloatLayout.__wrap(ret);
+
+function addHeapObject(obj) {
+    if (heap_next === heap.length) heap.push(heap.length + 1);
+    const idx = heap_next;
+    heap_next = heap[idx];
+
+    heap[idx] = obj;
+    return idx;
 }
 
 let WASM_VECTOR_LEN = 0;
@@ -82,11 +103,8 @@ function passStringToWasm0(arg, malloc, realloc) {
     return ptr;
 }
 
-function _assertClass(instance, klass) {
-    if (!(instance instanceof klass)) {
-        throw new Error(`expected instance of ${klass.name}`);
-    }
-    return instance.ptr;
+function isLikeNone(x) {
+    return x === undefined || x === null;
 }
 
 let cachedDataViewMemory0 = null;
@@ -97,223 +115,2506 @@ function getDataViewMemory0() {
     }
     return cachedDataViewMemory0;
 }
+
+function _assertClass(instance, klass) {
+    if (!(instance instanceof klass)) {
+        throw new Error(`expected instance of ${klass.name}`);
+    }
+    return instance.ptr;
+}
 /**
 * @param {string} decimal
 * @param {FloatLayout} layout
+* @param {RoundingMode} rounding
 * @returns {string}
 */
-export function decimal_to_binary(decimal, layout) {
-    let deferred2_0;
-    let deferred2_1;
+export function decimal_to_binary_with_rounding(decimal, layout, rounding) {
+    let deferred3_0;
+    let deferred3_1;
     try {
         const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
         const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
         _assertClass(layout, FloatLayout);
-        wasm.decimal_to_binary(retptr, ptr0, len0, layout.__wbg_ptr);
+        wasm.decimal_to_binary_with_rounding(retptr, ptr0, len0, layout.__wbg_ptr, rounding);
         var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
         var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        deferred2_0 = r0;
-        deferred2_1 = r1;
-        return getStringFromWasm0(r0, r1);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
     } finally {
         wasm.__wbindgen_add_to_stack_pointer(16);
-        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
     }
 }
 
 /**
 * @param {string} binary
 * @param {FloatLayout} layout
+* @param {DecimalMode} mode
 * @param {number} precision
 * @returns {string}
 */
-export function binary_to_decimal(binary, layout, precision) {
-    let deferred2_0;
-    let deferred2_1;
+export function binary_to_decimal_with_mode(binary, layout, mode, precision) {
+    let deferred3_0;
+    let deferred3_1;
     try {
         const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
         const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
         _assertClass(layout, FloatLayout);
-        wasm.binary_to_decimal(retptr, ptr0, len0, layout.__wbg_ptr, precision);
+        wasm.binary_to_decimal_with_mode(retptr, ptr0, len0, layout.__wbg_ptr, mode, precision);
         var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
         var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        deferred2_0 = r0;
-        deferred2_1 = r1;
-        return getStringFromWasm0(r0, r1);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* Accepts decimal literals such as `6.02e23`, hex float literals such as `0x1.8p+1`,
+* exact rationals such as `-22/7` or `2^-126`, `inf` and `nan`
+* @param {string} decimal
+* @param {FloatLayout} layout
+* @returns {string}
+*/
+export function decimal_to_binary(decimal, layout) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.decimal_to_binary(retptr, ptr0, len0, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* @param {string} decimal
+* @param {FloatLayout} layout
+* @param {RoundingMode} rounding
+* @returns {ConversionReport}
+*/
+export function decimal_to_binary_report(decimal, layout, rounding) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.decimal_to_binary_report(retptr, ptr0, len0, layout.__wbg_ptr, rounding);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return ConversionReport.__wrap(r0);
     } finally {
         wasm.__wbindgen_add_to_stack_pointer(16);
-        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
     }
 }
 
 /**
 * @param {string} binary
 * @param {FloatLayout} layout
+* @param {DecimalMode} mode
 * @param {number} precision
 * @returns {BinaryInfo}
 */
-export function binary_to_decimal_ext(binary, layout, precision) {
-    const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    const len0 = WASM_VECTOR_LEN;
-    _assertClass(layout, FloatLayout);
-    const ret = wasm.binary_to_decimal_ext(ptr0, len0, layout.__wbg_ptr, precision);
-    return BinaryInfo.__wrap(ret);
+export function binary_to_decimal_ext_with_mode(binary, layout, mode, precision) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.binary_to_decimal_ext_with_mode(retptr, ptr0, len0, layout.__wbg_ptr, mode, precision);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return BinaryInfo.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
+* @param {string} binary
+* @param {FloatLayout} layout
+* @returns {DecimalStream}
 */
-export const PredefinedLayout = Object.freeze({ Float16:0,"0":"Float16",Float32:1,"1":"Float32",Float64:2,"2":"Float64",Float128:3,"3":"Float128",Float256:4,"4":"Float256",Fp8E4M3:5,"5":"Fp8E4M3",Fp8E5M2:6,"6":"Fp8E5M2",BFloat16:7,"7":"BFloat16",TensorFloat32:8,"8":"TensorFloat32", });
+export function binary_to_decimal_stream(binary, layout) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.binary_to_decimal_stream(retptr, ptr0, len0, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return DecimalStream.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 
-const BinaryInfoFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_binaryinfo_free(ptr >>> 0, 1));
 /**
+* @param {string} binary
+* @param {FloatLayout} layout
+* @param {number} precision
+* @returns {string}
 */
-export class BinaryInfo {
-
-    static __wrap(ptr) {
-        ptr = ptr >>> 0;
-        const obj = Object.create(BinaryInfo.prototype);
-        obj.__wbg_ptr = ptr;
-        BinaryInfoFinalization.register(obj, obj.__wbg_ptr, obj);
-        return obj;
+export function binary_to_decimal(binary, layout, precision) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.binary_to_decimal(retptr, ptr0, len0, layout.__wbg_ptr, precision);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
     }
+}
 
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        BinaryInfoFinalization.unregister(this);
-        return ptr;
+/**
+* @param {string} binary
+* @param {FloatLayout} layout
+* @param {number} precision
+* @returns {BinaryInfo}
+*/
+export function binary_to_decimal_ext(binary, layout, precision) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.binary_to_decimal_ext(retptr, ptr0, len0, layout.__wbg_ptr, precision);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return BinaryInfo.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
     }
+}
 
-    free() {
-        const ptr = this.__destroy_into_raw();
-        wasm.__wbg_binaryinfo_free(ptr, 0);
-    }
-    /**
-    * @returns {string}
-    */
-    get decimal() {
-        let deferred1_0;
-        let deferred1_1;
-        try {
-            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.binaryinfo_decimal(retptr, this.__wbg_ptr);
-            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            deferred1_0 = r0;
-            deferred1_1 = r1;
-            return getStringFromWasm0(r0, r1);
-        } finally {
-            wasm.__wbindgen_add_to_stack_pointer(16);
-            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+/**
+* @param {string} decimal
+* @param {FloatLayout} layout
+* @param {RoundingMode} rounding
+* @param {OverflowPolicy} overflow
+* @returns {string}
+*/
+export function decimal_to_binary_with_overflow(decimal, layout, rounding, overflow) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.decimal_to_binary_with_overflow(retptr, ptr0, len0, layout.__wbg_ptr, rounding, overflow);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
         }
-    }
-    /**
-    * @returns {boolean}
-    */
-    get is_positive() {
-        const ret = wasm.binaryinfo_is_positive(this.__wbg_ptr);
-        return ret !== 0;
-    }
-    /**
-    * @returns {boolean}
-    */
-    get are_exponent_and_mantissa_valid() {
-        const ret = wasm.binaryinfo_are_exponent_and_mantissa_valid(this.__wbg_ptr);
-        return ret !== 0;
-    }
-    /**
-    * @returns {number}
-    */
-    get exponent() {
-        const ret = wasm.binaryinfo_exponent(this.__wbg_ptr);
-        return ret;
-    }
-    /**
-    * @returns {number}
-    */
-    get mantissa() {
-        const ret = wasm.binaryinfo_mantissa(this.__wbg_ptr);
-        return ret;
-    }
-    /**
-    * @returns {boolean}
-    */
-    get is_denormalized() {
-        const ret = wasm.binaryinfo_is_denormalized(this.__wbg_ptr);
-        return ret !== 0;
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
     }
 }
 
-const FloatLayoutFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_floatlayout_free(ptr >>> 0, 1));
 /**
+* Kind of non-canonical encoding of an explicit integer bit layout, `undefined` for
+* canonical ones
+* @param {string} binary
+* @param {FloatLayout} layout
+* @returns {NonCanonicalEncoding | undefined}
 */
-export class FloatLayout {
-
-    static __wrap(ptr) {
-        ptr = ptr >>> 0;
-        const obj = Object.create(FloatLayout.prototype);
-        obj.__wbg_ptr = ptr;
-        FloatLayoutFinalization.register(obj, obj.__wbg_ptr, obj);
-        return obj;
+export function binary_non_canonical_encoding(binary, layout) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.binary_non_canonical_encoding(retptr, ptr0, len0, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return r0 === 4 ? undefined : r0;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
     }
+}
 
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        FloatLayoutFinalization.unregister(this);
-        return ptr;
-    }
+/**
+* @param {string} binary
+* @param {FloatLayout} from
+* @param {FloatLayout} to
+* @param {RoundingMode} rounding
+* @returns {string}
+*/
+export function convert_binary(binary, from, to, rounding) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(from, FloatLayout);
+        _assertClass(to, FloatLayout);
+        wasm.convert_binary(retptr, ptr0, len0, from.__wbg_ptr, to.__wbg_ptr, rounding);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* @param {MathConstant} constant
+* @param {FloatLayout} layout
+* @param {RoundingMode} rounding
+* @returns {string}
+*/
+export function constant_to_binary(constant, layout, rounding) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        _assertClass(layout, FloatLayout);
+        wasm.constant_to_binary(retptr, constant, layout.__wbg_ptr, rounding);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr1 = r0;
+        var len1 = r1;
+        if (r3) {
+            ptr1 = 0; len1 = 0;
+            throw takeObject(r2);
+        }
+        deferred2_0 = ptr1;
+        deferred2_1 = len1;
+        return getStringFromWasm0(ptr1, len1);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+
+/**
+* @param {FloatLayout} layout
+* @returns {FloatCharacteristics}
+*/
+export function float_characteristics(layout) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        _assertClass(layout, FloatLayout);
+        wasm.float_characteristics(retptr, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return FloatCharacteristics.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+* @param {PredefinedLayout} layout_type
+* @returns {FloatLayout}
+*/
+export function get_predefined_layout(layout_type) {
+    const ret = wasm.get_predefined_layout(layout_type);
+    return FloatLayout.__wrap(ret);
+}
+
+/**
+* Expected value and variance of `decimal` rounded with `RoundingMode::Stochastic` over
+* all seeds
+* @param {string} decimal
+* @param {FloatLayout} layout
+* @returns {StochasticRoundingInfo}
+*/
+export function stochastic_rounding_info(decimal, layout) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.stochastic_rounding_info(retptr, ptr0, len0, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return StochasticRoundingInfo.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+* `%a`-style hexadecimal text of an encoding, e.g. `0x1.8p+1`. Hex floats are exact and
+* `decimal_to_binary` reads them back to the same encoding.
+* @param {string} binary
+* @param {FloatLayout} layout
+* @returns {string}
+*/
+export function binary_to_hex(binary, layout) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.binary_to_hex(retptr, ptr0, len0, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* `decimal` rounded stochastically, see `RoundingMode::Stochastic`
+* @param {string} decimal
+* @param {FloatLayout} layout
+* @param {bigint} seed
+* @returns {string}
+*/
+export function decimal_to_binary_stochastic(decimal, layout, seed) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.decimal_to_binary_stochastic(retptr, ptr0, len0, layout.__wbg_ptr, seed);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(takeObject(mem.getUint32(i, true)));
+    }
+    return result;
+}
+/**
+* Built-in layouts followed by the ones registered so far
+* @returns {(LayoutInfo)[]}
+*/
+export function list_layouts() {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.list_layouts(retptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 4, 4);
+        return v1;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+function passArrayJsValueToWasm0(array, malloc) {
+    const ptr = malloc(array.length * 4, 4) >>> 0;
+    const mem = getDataViewMemory0();
+    for (let i = 0; i < array.length; i++) {
+        mem.setUint32(ptr + 4 * i, addHeapObject(array[i]), true);
+    }
+    WASM_VECTOR_LEN = array.length;
+    return ptr;
+}
+/**
+* Makes `layout` available under `name` and `aliases` to `list_layouts` and
+* `FloatLayout::parse`. Names are case-insensitive and must neither be taken nor read as
+* an explicit descriptor like `e5m10`.
+* @param {string} name
+* @param {(string)[]} aliases
+* @param {string} description
+* @param {FloatLayout} layout
+* @returns {LayoutInfo}
+*/
+export function register_layout(name, aliases, description, layout) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArrayJsValueToWasm0(aliases, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(description, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len2 = WASM_VECTOR_LEN;
+        _assertClass(layout, FloatLayout);
+        wasm.register_layout(retptr, ptr0, len0, ptr1, len1, ptr2, len2, layout.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return LayoutInfo.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+* Layout with `name` as its name or one of its aliases, ignoring case
+* @param {string} name
+* @returns {LayoutInfo | undefined}
+*/
+export function find_layout(name) {
+    const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.find_layout(ptr0, len0);
+    return ret === 0 ? undefined : LayoutInfo.__wrap(ret);
+}
+
+function getArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
+/**
+* @param {string} decimal
+* @param {StorageLayout} storage
+* @param {RoundingMode} rounding
+* @returns {Uint8Array}
+*/
+export function decimal_to_bytes(decimal, storage, rounding) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(storage, StorageLayout);
+        wasm.decimal_to_bytes(retptr, ptr0, len0, storage.__wbg_ptr, rounding);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1, 1) >>> 0;
+    getUint8ArrayMemory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+/**
+* @param {Uint8Array} bytes
+* @param {StorageLayout} storage
+* @returns {string}
+*/
+export function bytes_to_hex(bytes, storage) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(storage, StorageLayout);
+        wasm.bytes_to_hex(retptr, ptr0, len0, storage.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* @param {Uint8Array} bytes
+* @param {StorageLayout} storage
+* @param {DecimalMode} mode
+* @param {number} precision
+* @returns {string}
+*/
+export function bytes_to_decimal(bytes, storage, mode, precision) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(storage, StorageLayout);
+        wasm.bytes_to_decimal(retptr, ptr0, len0, storage.__wbg_ptr, mode, precision);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* Bit string of the value stored in `bytes`, the input of every conversion that takes
+* an encoding
+* @param {Uint8Array} bytes
+* @param {StorageLayout} storage
+* @returns {string}
+*/
+export function bytes_to_binary(bytes, storage) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(storage, StorageLayout);
+        wasm.bytes_to_binary(retptr, ptr0, len0, storage.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+* @param {Uint8Array} bytes
+* @param {StorageLayout} from
+* @param {StorageLayout} to
+* @param {RoundingMode} rounding
+* @returns {Uint8Array}
+*/
+export function convert_bytes(bytes, from, to, rounding) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(from, StorageLayout);
+        _assertClass(to, StorageLayout);
+        wasm.convert_bytes(retptr, ptr0, len0, from.__wbg_ptr, to.__wbg_ptr, rounding);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+* Bytes that store the bit string `binary`, the output of every conversion that makes
+* an encoding
+* @param {string} binary
+* @param {StorageLayout} storage
+* @returns {Uint8Array}
+*/
+export function binary_to_bytes(binary, storage) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(storage, StorageLayout);
+        wasm.binary_to_bytes(retptr, ptr0, len0, storage.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_free(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+* Order of the bytes in a word, or of the words in a value
+*/
+export const ByteOrder = Object.freeze({
+/**
+* Most significant first
+*/
+BigEndian:0,"0":"BigEndian",
+/**
+* Least significant first
+*/
+LittleEndian:1,"1":"LittleEndian", });
+/**
+* How a conversion result relates to the exact input
+*/
+export const RoundingOutcome = Object.freeze({ Exact:0,"0":"Exact",
+/**
+* The result is greater than the input
+*/
+RoundedUp:1,"1":"RoundedUp",
+/**
+* The result is less than the input
+*/
+RoundedDown:2,"2":"RoundedDown", });
+/**
+*/
+export const DecimalMode = Object.freeze({
+/**
+* At most `precision` digits after the decimal point
+*/
+Rounded:0,"0":"Rounded",
+/**
+* Shortest string that converts back to the same encoding of the same layout
+*/
+Shortest:1,"1":"Shortest",
+/**
+* Full decimal expansion of the encoded value, nothing rounded away
+*/
+Exact:2,"2":"Exact", });
+/**
+* Encodings that only layouts with an explicit integer bit have, where the integer bit
+* contradicts the exponent
+*/
+export const NonCanonicalEncoding = Object.freeze({
+/**
+* Zero exponent with the integer bit set, read like the number with exponent one
+*/
+PseudoDenormal:0,"0":"PseudoDenormal",
+/**
+* Normal exponent with the integer bit clear
+*/
+Unnormal:1,"1":"Unnormal",
+/**
+* All-ones exponent with the integer bit and the fraction clear
+*/
+PseudoInfinity:2,"2":"PseudoInfinity",
+/**
+* All-ones exponent with the integer bit clear and a non-zero fraction
+*/
+PseudoNan:3,"3":"PseudoNan", });
+/**
+* The rounding modes that JS can pass directly, as enums exported through wasm_bindgen
+* cannot carry data. Stochastic rounding takes its seed as a separate argument there.
+*/
+export const RoundingMode = Object.freeze({ TiesToEven:0,"0":"TiesToEven",TiesToAway:1,"1":"TiesToAway",TowardPositive:2,"2":"TowardPositive",TowardNegative:3,"3":"TowardNegative",TowardZero:4,"4":"TowardZero",ToOdd:5,"5":"ToOdd", });
+/**
+*/
+export const PredefinedLayout = Object.freeze({ Float16:0,"0":"Float16",Float32:1,"1":"Float32",Float64:2,"2":"Float64",Float128:3,"3":"Float128",Float256:4,"4":"Float256",Fp8E4M3:5,"5":"Fp8E4M3",Fp8E5M2:6,"6":"Fp8E5M2",BFloat16:7,"7":"BFloat16",TensorFloat32:8,"8":"TensorFloat32",X87Float80:9,"9":"X87Float80",Fp8E4M3FN:10,"10":"Fp8E4M3FN",Fp8E4M3FNUZ:11,"11":"Fp8E4M3FNUZ",Fp8E5M2FNUZ:12,"12":"Fp8E5M2FNUZ",Fp8E8M0:13,"13":"Fp8E8M0",Fp6E3M2:14,"14":"Fp6E3M2",Fp6E2M3:15,"15":"Fp6E2M3",Fp4E2M1:16,"16":"Fp4E2M1",TiShort:17,"17":"TiShort",TiSingle:18,"18":"TiSingle",TiExtended:19,"19":"TiExtended", });
+/**
+* Order of the fields in an encoding, from the most significant bit down
+*/
+export const FieldOrder = Object.freeze({
+/**
+* As in IEEE 754
+*/
+SignExponentMantissa:0,"0":"SignExponentMantissa",SignMantissaExponent:1,"1":"SignMantissaExponent",
+/**
+* As in the TI C3x and C4x DSPs
+*/
+ExponentSignMantissa:2,"2":"ExponentSignMantissa",ExponentMantissaSign:3,"3":"ExponentMantissaSign",MantissaSignExponent:4,"4":"MantissaSignExponent",MantissaExponentSign:5,"5":"MantissaExponentSign", });
+/**
+* What a conversion produces for magnitudes beyond the largest finite number
+*/
+export const OverflowPolicy = Object.freeze({
+/**
+* Infinity or the largest finite number, as the rounding mode dictates
+*/
+Ieee:0,"0":"Ieee",
+/**
+* Always the largest finite number of the same sign, infinite inputs included
+* ("satfinite" in CUDA and the OCP FP8 specification)
+*/
+SatFinite:1,"1":"SatFinite", });
+/**
+* Which encodings of a layout are infinities and NaNs instead of numbers
+*/
+export const SpecialValuePolicy = Object.freeze({
+/**
+* The all-ones exponent holds the infinities and NaNs
+*/
+Ieee:0,"0":"Ieee",
+/**
+* No infinities, only the all-ones exponent and mantissa is NaN, as in OCP E4M3FN
+*/
+FiniteWithNan:1,"1":"FiniteWithNan",
+/**
+* Every encoding is a number, as in the OCP MX FP6 and FP4 types
+*/
+FiniteOnly:2,"2":"FiniteOnly",
+/**
+* No infinities and no negative zero, its encoding is the only NaN, as in the
+* E4M3FNUZ and E5M2FNUZ types of AMD and Graphcore
+*/
+NanIsNegativeZero:3,"3":"NanIsNegativeZero", });
+/**
+* How the exponent field stores the exponent
+*/
+export const ExponentEncoding = Object.freeze({
+/**
+* The exponent plus `exponent_bias`
+*/
+Biased:0,"0":"Biased",
+/**
+* Two's complement, which is the exponent biased by `2^(e - 1)` with the top bit
+* flipped. Its most negative value is the zero exponent.
+*/
+TwosComplement:1,"1":"TwosComplement", });
+/**
+* How the sign and mantissa fields store the significand
+*/
+export const MantissaEncoding = Object.freeze({
+/**
+* The sign bit negates the significand `1.f`
+*/
+SignMagnitude:0,"0":"SignMagnitude",
+/**
+* The sign bit is the top bit of the two's-complement significand `01.f` or `10.f`,
+* i.e. `1 + f` or `-2 + f`. These layouts have no subnormals, the zero exponent holds
+* zero alone, and their negative numbers reach one binade further than the positive
+* ones, as in the TI C3x and C4x DSPs.
+*/
+TwosComplement:1,"1":"TwosComplement", });
+/**
+* Mathematical constants that can be materialized into any layout, named after their
+* counterparts in `std::f64::consts`
+*/
+export const MathConstant = Object.freeze({
+/**
+* π
+*/
+Pi:0,"0":"Pi",
+/**
+* 2π
+*/
+Tau:1,"1":"Tau",
+/**
+* π/2
+*/
+FracPi2:2,"2":"FracPi2",
+/**
+* π/3
+*/
+FracPi3:3,"3":"FracPi3",
+/**
+* π/4
+*/
+FracPi4:4,"4":"FracPi4",
+/**
+* π/6
+*/
+FracPi6:5,"5":"FracPi6",
+/**
+* π/8
+*/
+FracPi8:6,"6":"FracPi8",
+/**
+* 1/π
+*/
+Frac1Pi:7,"7":"Frac1Pi",
+/**
+* 2/π
+*/
+Frac2Pi:8,"8":"Frac2Pi",
+/**
+* 2/sqrt(π)
+*/
+Frac2SqrtPi:9,"9":"Frac2SqrtPi",
+/**
+* sqrt(2)
+*/
+Sqrt2:10,"10":"Sqrt2",
+/**
+* 1/sqrt(2)
+*/
+Frac1Sqrt2:11,"11":"Frac1Sqrt2",
+/**
+* Euler's number e
+*/
+E:12,"12":"E",
+/**
+* log2(e)
+*/
+Log2E:13,"13":"Log2E",
+/**
+* log2(10)
+*/
+Log2Of10:14,"14":"Log2Of10",
+/**
+* log10(e)
+*/
+Log10E:15,"15":"Log10E",
+/**
+* log10(2)
+*/
+Log10Of2:16,"16":"Log10Of2",
+/**
+* ln(2)
+*/
+Ln2:17,"17":"Ln2",
+/**
+* ln(10)
+*/
+Ln10:18,"18":"Ln10", });
+/**
+* Who defined a layout
+*/
+export const LayoutOrigin = Object.freeze({
+/**
+* IEEE 754 and formats that follow its rules
+*/
+Ieee:0,"0":"Ieee",
+/**
+* Open Compute Project, the OFP8 and Microscaling (MX) specifications
+*/
+Ocp:1,"1":"Ocp",Nvidia:2,"2":"Nvidia",Google:3,"3":"Google",Intel:4,"4":"Intel",Graphcore:5,"5":"Graphcore",
+/**
+* The TMS320C3x/C4x DSP formats
+*/
+TexasInstruments:6,"6":"TexasInstruments",
+/**
+* Registered at runtime with `register_layout`
+*/
+Custom:7,"7":"Custom", });
+
+const BinaryInfoFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_binaryinfo_free(ptr >>> 0, 1));
+/**
+*/
+export class BinaryInfo {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(BinaryInfo.prototype);
+        obj.__wbg_ptr = ptr;
+        BinaryInfoFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        BinaryInfoFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_binaryinfo_free(ptr, 0);
+    }
+    /**
+    * @returns {boolean}
+    */
+    get is_positive() {
+        const ret = wasm.binaryinfo_is_positive(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * @returns {boolean}
+    */
+    get is_denormalized() {
+        const ret = wasm.binaryinfo_is_denormalized(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * @returns {boolean}
+    */
+    get are_exponent_and_mantissa_valid() {
+        const ret = wasm.binaryinfo_are_exponent_and_mantissa_valid(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * @returns {string}
+    */
+    get decimal() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.binaryinfo_decimal(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {number}
+    */
+    get exponent() {
+        const ret = wasm.binaryinfo_exponent(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {number}
+    */
+    get mantissa() {
+        const ret = wasm.binaryinfo_mantissa(this.__wbg_ptr);
+        return ret;
+    }
+}
+
+const ConversionReportFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_conversionreport_free(ptr >>> 0, 1));
+/**
+* Result of `decimal_to_binary_report`: the encoding together with the error it carries.
+* Errors are signed as `result - input` and reported in `f64`, so errors of the widest
+* layouts may underflow to zero even though `is_exact` is false.
+*/
+export class ConversionReport {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(ConversionReport.prototype);
+        obj.__wbg_ptr = ptr;
+        ConversionReportFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ConversionReportFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_conversionreport_free(ptr, 0);
+    }
+    /**
+    * @returns {number}
+    */
+    get absolute_error() {
+        const ret = wasm.binaryinfo_mantissa(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Absolute error over the magnitude of the input
+    * @returns {number}
+    */
+    get relative_error() {
+        const ret = wasm.conversionreport_relative_error(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {string}
+    */
+    get binary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.conversionreport_binary(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {RoundingOutcome}
+    */
+    get outcome() {
+        const ret = wasm.conversionreport_outcome(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {boolean}
+    */
+    get is_exact() {
+        const ret = wasm.conversionreport_is_exact(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * Absolute error in units in the last place of the input's binade, clamped to the
+    * finite range of the layout
+    * @returns {number}
+    */
+    get ulp_error() {
+        const ret = wasm.conversionreport_ulp_error(this.__wbg_ptr);
+        return ret;
+    }
+}
+
+const DecimalStreamFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_decimalstream_free(ptr >>> 0, 1));
+/**
+* Exact decimal expansion handed out piece by piece, so that huge expansions
+* (e.g. the smallest Float256 subnormal) can be shown before they are fully computed
+*/
+export class DecimalStream {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(DecimalStream.prototype);
+        obj.__wbg_ptr = ptr;
+        DecimalStreamFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DecimalStreamFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_decimalstream_free(ptr, 0);
+    }
+    /**
+    * Next piece of at most `max_len` characters, `None` once the expansion is over
+    * @param {number} max_len
+    * @returns {string | undefined}
+    */
+    next_chunk(max_len) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.decimalstream_next_chunk(retptr, this.__wbg_ptr, max_len);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            let v1;
+            if (r0 !== 0) {
+                v1 = getStringFromWasm0(r0, r1).slice();
+                wasm.__wbindgen_free(r0, r1 * 1, 1);
+            }
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+
+const DoubleRoundingReportFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_doubleroundingreport_free(ptr >>> 0, 1));
+/**
+* Outcome of rounding a number through a chain of layouts compared to rounding it
+* directly into the last one
+*/
+export class DoubleRoundingReport {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(DoubleRoundingReport.prototype);
+        obj.__wbg_ptr = ptr;
+        DoubleRoundingReportFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DoubleRoundingReportFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_doubleroundingreport_free(ptr, 0);
+    }
+    /**
+    * @returns {boolean}
+    */
+    get is_double_rounding_error() {
+        const ret = wasm.doubleroundingreport_is_double_rounding_error(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * Encodings in every layout of the chain, in order
+    * @returns {(string)[]}
+    */
+    get steps() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.doubleroundingreport_steps(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
+            wasm.__wbindgen_free(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * Encoding in the last layout when rounded directly
+    * @returns {string}
+    */
+    get direct() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.doubleroundingreport_direct(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Encoding in the last layout when rounded through the chain
+    * @returns {string}
+    */
+    get chained() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.doubleroundingreport_chained(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+}
+
+const EncodedValueFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_encodedvalue_free(ptr >>> 0, 1));
+/**
+* One encoding together with the layout it belongs to. Its text is the layout, a colon
+* and the bits, in hex when they fill whole digits, e.g. `fp32:0x3f800000` or
+* `e3m3:0b0011100`.
+*/
+export class EncodedValue {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(EncodedValue.prototype);
+        obj.__wbg_ptr = ptr;
+        EncodedValueFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        EncodedValueFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_encodedvalue_free(ptr, 0);
+    }
+    /**
+    * @returns {string}
+    */
+    toString() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.encodedvalue_toString(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * `binary` is a bit string of exactly the layout size
+    * @param {string} binary
+    * @param {FloatLayout} layout
+    */
+    constructor(binary, layout) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(binary, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            _assertClass(layout, FloatLayout);
+            wasm.encodedvalue_new(retptr, ptr0, len0, layout.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            this.__wbg_ptr = r0 >>> 0;
+            EncodedValueFinalization.register(this, this.__wbg_ptr, this);
+            return this;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * The encoding as a bit string, the input of the conversion functions
+    * @returns {string}
+    */
+    get bits() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.encodedvalue_bits(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * See the `FromStr` implementation
+    * @param {string} text
+    * @returns {EncodedValue}
+    */
+    static parse(text) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encodedvalue_parse(retptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return EncodedValue.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @returns {FloatLayout}
+    */
+    get layout() {
+        const ret = wasm.encodedvalue_layout(this.__wbg_ptr);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * Reads one value stored as `storage` says
+    * @param {Uint8Array} bytes
+    * @param {StorageLayout} storage
+    * @returns {EncodedValue}
+    */
+    static from_bytes(bytes, storage) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+            const len0 = WASM_VECTOR_LEN;
+            _assertClass(storage, StorageLayout);
+            wasm.encodedvalue_from_bytes(retptr, ptr0, len0, storage.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return EncodedValue.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * Bytes of the value stored as `storage` says, which has to be for the same layout
+    * @param {StorageLayout} storage
+    * @returns {Uint8Array}
+    */
+    to_bytes(storage) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(storage, StorageLayout);
+            wasm.encodedvalue_to_bytes(retptr, this.__wbg_ptr, storage.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+            if (r3) {
+                throw takeObject(r2);
+            }
+            var v1 = getArrayU8FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_free(r0, r1 * 1, 1);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+
+const FloatCharacteristicsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_floatcharacteristics_free(ptr >>> 0, 1));
+/**
+* `<float.h>` characteristics of a layout, the `FLT_*` and `DBL_*` macros of C or the
+* `f32::` and `f64::` constants of Rust. Like C, exponents count significands of the form
+* `0.1xxx`, so `min_exp` and `max_exp` are one above the binary exponents of
+* `min_positive` and `max`. Numbers come as the shortest decimal that reads back to the
+* same encoding, the integer as its exact digits, and in the `_binary` getters as the
+* encoding itself.
+*/
+export class FloatCharacteristics {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(FloatCharacteristics.prototype);
+        obj.__wbg_ptr = ptr;
+        FloatCharacteristicsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        FloatCharacteristicsFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_floatcharacteristics_free(ptr, 0);
+    }
+    /**
+    * Largest power of ten that is a finite number
+    * @returns {number}
+    */
+    get max_10_exp() {
+        const ret = wasm.floatcharacteristics_max_10_exp(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {string}
+    */
+    get max_binary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_max_binary(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Smallest power of ten that is a normal number
+    * @returns {number}
+    */
+    get min_10_exp() {
+        const ret = wasm.floatcharacteristics_min_10_exp(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Decimal digits that tell every two encodings apart, `DECIMAL_DIG`
+    * @returns {number}
+    */
+    get decimal_dig() {
+        const ret = wasm.floatcharacteristics_decimal_dig(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * Smallest positive normal number, `MIN` in C
+    * @returns {string}
+    */
+    get min_positive() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_min_positive(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {string}
+    */
+    get epsilon_binary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_epsilon_binary(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {string}
+    */
+    get true_min_binary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_true_min_binary(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {string}
+    */
+    get min_positive_binary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_min_positive_binary(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Largest integer `n` such that every integer from 1 to `n` is a number of the layout
+    * @returns {string}
+    */
+    get max_consecutive_integer() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_max_consecutive_integer(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {string}
+    */
+    get max_consecutive_integer_binary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_max_consecutive_integer_binary(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Decimal digits that survive a round trip through the layout, `DIG`
+    * @returns {number}
+    */
+    get dig() {
+        const ret = wasm.floatcharacteristics_dig(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * @returns {string}
+    */
+    get max() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_max(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Distance from 1 to the next larger number
+    * @returns {string}
+    */
+    get epsilon() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_epsilon(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {number}
+    */
+    get max_exp() {
+        const ret = wasm.floatcharacteristics_max_exp(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {number}
+    */
+    get min_exp() {
+        const ret = wasm.floatcharacteristics_min_exp(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Significand bits including the leading one, `MANT_DIG`
+    * @returns {number}
+    */
+    get mant_dig() {
+        const ret = wasm.floatcharacteristics_mant_dig(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * Smallest positive number, subnormal unless the layout has no subnormals
+    * @returns {string}
+    */
+    get true_min() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatcharacteristics_true_min(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+}
+
+const FloatLayoutFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_floatlayout_free(ptr >>> 0, 1));
+/**
+*/
+export class FloatLayout {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(FloatLayout.prototype);
+        obj.__wbg_ptr = ptr;
+        FloatLayoutFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        FloatLayoutFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_floatlayout_free(ptr, 0);
+    }
+    /**
+    * @returns {boolean}
+    */
+    has_infinity() {
+        const ret = wasm.floatlayout_has_infinity(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * Significand bits including the leading one, hidden or not
+    * @returns {number}
+    */
+    get_precision() {
+        const ret = wasm.floatlayout_get_precision(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * @returns {number}
+    */
+    get_sign_size() {
+        const ret = wasm.floatlayout_get_sign_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * @returns {string}
+    */
+    toString() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatlayout_toString(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Layouts without fraction bits have no subnormals, their zero exponent is a normal
+    * one like in OCP E8M0. Two's-complement mantissas keep it for zero instead.
+    * @returns {boolean}
+    */
+    has_subnormals() {
+        const ret = wasm.floatlayout_has_subnormals(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * @returns {FieldOrder}
+    */
+    get_field_order() {
+        const ret = wasm.floatlayout_get_field_order(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Copy of the layout that stores its fields in `field_order`
+    * @param {FieldOrder} field_order
+    * @returns {FloatLayout}
+    */
+    with_field_order(field_order) {
+        const ret = wasm.floatlayout_with_field_order(this.__wbg_ptr, field_order);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * @returns {number}
+    */
+    get_exponent_bias() {
+        const ret = wasm.floatlayout_get_exponent_bias(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * @returns {number}
+    */
+    get_exponent_size() {
+        const ret = wasm.floatlayout_get_exponent_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * Mantissa bits below the leading significand bit, i.e. without any explicit integer
+    * bit
+    * @returns {number}
+    */
+    get_fraction_size() {
+        const ret = wasm.floatlayout_get_fraction_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * @returns {number}
+    */
+    get_mantissa_size() {
+        const ret = wasm.floatlayout_get_mantissa_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * @returns {boolean}
+    */
+    has_negative_zero() {
+        const ret = wasm.floatlayout_has_negative_zero(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * @returns {SpecialValuePolicy}
+    */
+    get_special_values() {
+        const ret = wasm.floatlayout_get_special_values(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Copy of the layout with another exponent bias
+    * @param {number} exponent_bias
+    * @returns {FloatLayout}
+    */
+    with_exponent_bias(exponent_bias) {
+        const ret = wasm.floatlayout_with_exponent_bias(this.__wbg_ptr, exponent_bias);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * Copy of the layout that encodes infinities and NaNs as `special_values` says
+    * @param {SpecialValuePolicy} special_values
+    * @returns {FloatLayout}
+    */
+    with_special_values(special_values) {
+        const ret = wasm.floatlayout_with_special_values(this.__wbg_ptr, special_values);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * @returns {ExponentEncoding}
+    */
+    get_exponent_encoding() {
+        const ret = wasm.floatlayout_get_exponent_encoding(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {MantissaEncoding}
+    */
+    get_mantissa_encoding() {
+        const ret = wasm.floatlayout_get_mantissa_encoding(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Copy of the layout whose exponent field is encoded as `exponent_encoding`. A
+    * two's-complement exponent implies the bias `2^(e - 1)`, which the copy gets.
+    * @param {ExponentEncoding} exponent_encoding
+    * @returns {FloatLayout}
+    */
+    with_exponent_encoding(exponent_encoding) {
+        const ret = wasm.floatlayout_with_exponent_encoding(this.__wbg_ptr, exponent_encoding);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * Copy of the layout whose significand is encoded as `mantissa_encoding`
+    * @param {MantissaEncoding} mantissa_encoding
+    * @returns {FloatLayout}
+    */
+    with_mantissa_encoding(mantissa_encoding) {
+        const ret = wasm.floatlayout_with_mantissa_encoding(this.__wbg_ptr, mantissa_encoding);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * @returns {boolean}
+    */
+    has_explicit_integer_bit() {
+        const ret = wasm.floatlayout_has_explicit_integer_bit(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * Copy of the layout whose top mantissa bit is (or is not) an explicit integer bit
+    * @param {boolean} explicit_integer_bit
+    * @returns {FloatLayout}
+    */
+    with_explicit_integer_bit(explicit_integer_bit) {
+        const ret = wasm.floatlayout_with_explicit_integer_bit(this.__wbg_ptr, explicit_integer_bit);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * @param {number} sign
+    * @param {number} exponent
+    * @param {number} mantissa
+    * @param {number} exponent_bias
+    */
+    constructor(sign, exponent, mantissa, exponent_bias) {
+        const ret = wasm.floatlayout_new(sign, exponent, mantissa, exponent_bias);
+        this.__wbg_ptr = ret >>> 0;
+        FloatLayoutFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+    * See the `FromStr` implementation
+    * @param {string} descriptor
+    * @returns {FloatLayout}
+    */
+    static parse(descriptor) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(descriptor, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.floatlayout_parse(retptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return FloatLayout.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @returns {boolean}
+    */
+    has_nan() {
+        const ret = wasm.floatlayout_has_nan(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * `new` that rejects layouts the encoding routines cannot handle, see `validate`
+    * @param {number} sign
+    * @param {number} exponent
+    * @param {number} mantissa
+    * @param {number} exponent_bias
+    * @returns {FloatLayout}
+    */
+    static try_new(sign, exponent, mantissa, exponent_bias) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatlayout_try_new(retptr, sign, exponent, mantissa, exponent_bias);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return FloatLayout.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @returns {number}
+    */
+    get_size() {
+        const ret = wasm.floatlayout_get_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+    * Zero is the smallest subnormal, without subnormals there is none unless the zero
+    * exponent is reserved for it
+    * @returns {boolean}
+    */
+    has_zero() {
+        const ret = wasm.floatlayout_has_zero(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+    * Checks that every encoding routine works on the layout: IEEE NaNs need a quiet bit
+    * and a payload bit besides any integer bit, an explicit integer bit needs a fraction
+    * bit, two's-complement fields need their implied bias and sign, 1 has to be a normal
+    * number and the whole encoding has to fit into a `BitField`
+    */
+    validate() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.floatlayout_validate(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+
+const LayoutChainFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_layoutchain_free(ptr >>> 0, 1));
+/**
+* Chain of layouts for `check_double_rounding`, assembled one layout at a time from JS
+*/
+export class LayoutChain {
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LayoutChainFinalization.unregister(this);
+        return ptr;
+    }
 
     free() {
         const ptr = this.__destroy_into_raw();
-        wasm.__wbg_floatlayout_free(ptr, 0);
+        wasm.__wbg_layoutchain_free(ptr, 0);
     }
     /**
-    * @param {number} sign
-    * @param {number} exponent
-    * @param {number} mantissa
-    * @param {number} exponent_bias
+    * @param {string} decimal
+    * @param {RoundingMode} intermediate_rounding
+    * @param {RoundingMode} final_rounding
+    * @returns {DoubleRoundingReport}
     */
-    constructor(sign, exponent, mantissa, exponent_bias) {
-        const ret = wasm.floatlayout_new(sign, exponent, mantissa, exponent_bias);
+    check_double_rounding(decimal, intermediate_rounding, final_rounding) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(decimal, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.layoutchain_check_double_rounding(retptr, this.__wbg_ptr, ptr0, len0, intermediate_rounding, final_rounding);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return DoubleRoundingReport.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    */
+    constructor() {
+        const ret = wasm.layoutchain_new();
         this.__wbg_ptr = ret >>> 0;
-        FloatLayoutFinalization.register(this, this.__wbg_ptr, this);
+        LayoutChainFinalization.register(this, this.__wbg_ptr, this);
         return this;
     }
     /**
+    * @param {FloatLayout} layout
+    */
+    push(layout) {
+        _assertClass(layout, FloatLayout);
+        wasm.layoutchain_push(this.__wbg_ptr, layout.__wbg_ptr);
+    }
+}
+
+const LayoutInfoFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_layoutinfo_free(ptr >>> 0, 1));
+/**
+* Layout known by name, as listed by `list_layouts`
+*/
+export class LayoutInfo {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(LayoutInfo.prototype);
+        obj.__wbg_ptr = ptr;
+        LayoutInfoFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LayoutInfoFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_layoutinfo_free(ptr, 0);
+    }
+    /**
+    * @returns {string}
+    */
+    get description() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layoutinfo_description(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {SpecialValuePolicy}
+    */
+    get special_values() {
+        const ret = wasm.layoutinfo_special_values(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {string}
+    */
+    get name() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layoutinfo_name(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * @returns {FloatLayout}
+    */
+    get layout() {
+        const ret = wasm.layoutinfo_layout(this.__wbg_ptr);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * @returns {LayoutOrigin}
+    */
+    get origin() {
+        const ret = wasm.layoutinfo_origin(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {(string)[]}
+    */
+    get aliases() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layoutinfo_aliases(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
+            wasm.__wbindgen_free(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+
+const StochasticRoundingInfoFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_stochasticroundinginfo_free(ptr >>> 0, 1));
+/**
+* Distribution of stochastically rounding a number into a layout. The result is one of
+* the two encodings around the number, the one of larger magnitude with
+* `probability_away`.
+*/
+export class StochasticRoundingInfo {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(StochasticRoundingInfo.prototype);
+        obj.__wbg_ptr = ptr;
+        StochasticRoundingInfoFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        StochasticRoundingInfoFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_stochasticroundinginfo_free(ptr, 0);
+    }
+    /**
+    * Encoding of the neighbour toward zero
+    * @returns {string}
+    */
+    get toward_zero() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.stochasticroundinginfo_toward_zero(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Encoding of the neighbour away from zero, infinity past the largest finite number
+    * @returns {string}
+    */
+    get away_from_zero() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.stochasticroundinginfo_away_from_zero(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+    * Mean of the rounded value, the input itself up to the 2^-64 resolution of the draw
     * @returns {number}
     */
-    get_size() {
-        const ret = wasm.floatlayout_get_size(this.__wbg_ptr);
-        return ret >>> 0;
+    get expected_value() {
+        const ret = wasm.conversionreport_relative_error(this.__wbg_ptr);
+        return ret;
     }
     /**
     * @returns {number}
     */
-    get_sign_size() {
-        const ret = wasm.floatlayout_get_sign_size(this.__wbg_ptr);
-        return ret >>> 0;
+    get probability_away() {
+        const ret = wasm.binaryinfo_mantissa(this.__wbg_ptr);
+        return ret;
     }
     /**
     * @returns {number}
     */
-    get_exponent_size() {
-        const ret = wasm.floatlayout_get_exponent_size(this.__wbg_ptr);
-        return ret >>> 0;
+    get variance() {
+        const ret = wasm.conversionreport_ulp_error(this.__wbg_ptr);
+        return ret;
+    }
+}
+
+const StorageLayoutFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_storagelayout_free(ptr >>> 0, 1));
+/**
+* How the encodings of a layout sit in memory. The value is split into words of
+* `word_bytes` bytes, the bytes of each word follow `byte_order` and the words
+* `word_order`, which covers the mixed-endian PDP-11, VAX and ARM FPA formats. Layouts
+* that do not fill whole bytes are stored in the low bits of the fewest bytes that hold
+* them, with the padding bits above zero.
+*/
+export class StorageLayout {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(StorageLayout.prototype);
+        obj.__wbg_ptr = ptr;
+        StorageLayoutFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        StorageLayoutFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_storagelayout_free(ptr, 0);
+    }
+    /**
+    * @param {FloatLayout} layout
+    * @returns {StorageLayout}
+    */
+    static big_endian(layout) {
+        _assertClass(layout, FloatLayout);
+        const ret = wasm.storagelayout_big_endian(layout.__wbg_ptr);
+        return StorageLayout.__wrap(ret);
+    }
+    /**
+    * @returns {ByteOrder}
+    */
+    get byte_order() {
+        const ret = wasm.storagelayout_byte_order(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Little-endian 16-bit words, most significant word first, as the floats of the
+    * PDP-11 and the VAX
+    * @param {FloatLayout} layout
+    * @returns {StorageLayout}
+    */
+    static pdp_endian(layout) {
+        _assertClass(layout, FloatLayout);
+        const ret = wasm.storagelayout_pdp_endian(layout.__wbg_ptr);
+        return StorageLayout.__wrap(ret);
+    }
+    /**
+    * Copy of the storage that splits values into words of `word_bytes` bytes stored in
+    * `word_order`
+    * @param {number} word_bytes
+    * @param {ByteOrder} word_order
+    * @returns {StorageLayout}
+    */
+    with_words(word_bytes, word_order) {
+        const ret = wasm.storagelayout_with_words(this.__wbg_ptr, word_bytes, word_order);
+        return StorageLayout.__wrap(ret);
     }
     /**
     * @returns {number}
     */
-    get_mantissa_size() {
-        const ret = wasm.floatlayout_get_mantissa_size(this.__wbg_ptr);
+    get word_bytes() {
+        const ret = wasm.storagelayout_word_bytes(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * @returns {ByteOrder}
+    */
+    get word_order() {
+        const ret = wasm.storagelayout_word_order(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+    * Bytes taken by one value, including any padding bits
+    * @returns {number}
+    */
+    get_byte_size() {
+        const ret = wasm.storagelayout_get_byte_size(this.__wbg_ptr);
         return ret >>> 0;
     }
+    /**
+    * @param {FloatLayout} layout
+    * @returns {StorageLayout}
+    */
+    static little_endian(layout) {
+        _assertClass(layout, FloatLayout);
+        const ret = wasm.storagelayout_little_endian(layout.__wbg_ptr);
+        return StorageLayout.__wrap(ret);
+    }
+    /**
+    * Little-endian 32-bit words, most significant word first, as the doubles of the ARM
+    * floating point accelerator (FPA)
+    * @param {FloatLayout} layout
+    * @returns {StorageLayout}
+    */
+    static arm_fpa_endian(layout) {
+        _assertClass(layout, FloatLayout);
+        const ret = wasm.storagelayout_arm_fpa_endian(layout.__wbg_ptr);
+        return StorageLayout.__wrap(ret);
+    }
+    /**
+    * The whole value as a single word of `byte_order`
+    * @param {FloatLayout} layout
+    * @param {ByteOrder} byte_order
+    */
+    constructor(layout, byte_order) {
+        _assertClass(layout, FloatLayout);
+        const ret = wasm.storagelayout_new(layout.__wbg_ptr, byte_order);
+        this.__wbg_ptr = ret >>> 0;
+        StorageLayoutFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+    * @returns {FloatLayout}
+    */
+    get layout() {
+        const ret = wasm.storagelayout_layout(this.__wbg_ptr);
+        return FloatLayout.__wrap(ret);
+    }
+    /**
+    * Checks the layout and that the words evenly split the stored bytes
+    */
+    validate() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.storagelayout_validate(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
 }
 
 async function __wbg_load(module, imports) {
@@ -350,6 +2651,29 @@ async function __wbg_load(module, imports) {
 function __wbg_get_imports() {
     const imports = {};
     imports.wbg = {};
+    imports.wbg.__wbindgen_object_drop_ref = function(arg0) {
+        takeObject(arg0);
+    };
+    imports.wbg.__wbg_layoutinfo_new = function(arg0) {
+        const ret = LayoutInfo.__wrap(arg0);
+        return addHeapObject(ret);
+    };
+    imports.wbg.__wbindgen_error_new = function(arg0, arg1) {
+        const ret = new Error(getStringFromWasm0(arg0, arg1));
+        return addHeapObject(ret);
+    };
+    imports.wbg.__wbindgen_string_get = function(arg0, arg1) {
+        const obj = getObject(arg1);
+        const ret = typeof(obj) === 'string' ? obj : undefined;
+        var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
+        getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+        getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+    };
+    imports.wbg.__wbindgen_string_new = function(arg0, arg1) {
+        const ret = getStringFromWasm0(arg0, arg1);
+        return addHeapObject(ret);
+    };
     imports.wbg.__wbindgen_throw = function(arg0, arg1) {
         throw new Error(getStringFromWasm0(arg0, arg1));
     };
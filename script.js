@@ -1,13 +1,15 @@
-import init, { decimal_to_binary, binary_to_decimal, binary_to_decimal_ext, get_predefined_layout, PredefinedLayout } from './ieee754_web.js';
+import init, { decimal_to_binary, binary_to_decimal, binary_to_decimal_ext, find_layout, list_layouts, FieldOrder } from './ieee754_web.js';
 
 await init();
 
 const DECIMAL_PRECISION = 20
 
-const DEFALT_LAYOUT = get_predefined_layout(PredefinedLayout.Float32)
+const DEFALT_LAYOUT_NAME = 'fp32'
+const DEFALT_LAYOUT = find_layout(DEFALT_LAYOUT_NAME).layout
 let current_layout = DEFALT_LAYOUT
 
 const decInputField = document.getElementById('dec-input-field')
+const decErrorText = document.getElementById('dec-error-text')
 
 const copyDecButton = document.getElementById('button-copy-dec')
 const copyBinButton = document.getElementById('button-copy-bin')
@@ -32,14 +34,35 @@ const normalizedLabel = document.getElementById('normalized-section')
 
 const layoutCombo = document.getElementById('layout-combo')
 
+// the selector lists every layout the registry knows that the sign, exponent and mantissa
+// rows can show, i.e. with the sign first
+for (const info of list_layouts()) {
+    if (info.layout.get_field_order() != FieldOrder.SignExponentMantissa) {
+        continue
+    }
+
+    var option = document.createElement("option")
+
+    option.value = info.name
+    option.text = info.name
+    option.title = info.description
+    option.selected = info.name == DEFALT_LAYOUT_NAME
+
+    layoutCombo.add(option)
+}
+
 layoutCombo.onchange = () => {
-    ChangeLayout(get_predefined_layout(PredefinedLayout[layoutCombo.value]))
+    ChangeLayout(find_layout(layoutCombo.value).layout)
     SetThree()
 }
 
 function ChangeLayout(layout) {
     current_layout = layout
 
+    zeroButton.disabled = !layout.has_zero()
+    infButton.disabled = !layout.has_infinity()
+    nanButton.disabled = !layout.has_nan()
+
     // sign bit
     {
         let checkboxes = []
@@ -113,7 +136,7 @@ function ChangeLayout(layout) {
     {
         let fields = []
 
-        const N = layout.get_size() / 4
+        const N = Math.ceil(layout.get_size() / 4)
 
         for(let i = 0; i < N; ++i) {
             var field = document.createElement("input")
@@ -121,11 +144,13 @@ function ChangeLayout(layout) {
             field.type = 'text'
             field.id = 'bin-input-field-' + N
             field.classList.add('bin-input-field')
+            // the last field holds what is left of the bits
+            field.maxLength = Math.min(4, layout.get_size() - i * 4)
 
             // bin edit
             field.oninput = () => {
                 let filtered = ''
-                for(let i = 0; i < 4; ++i) {
+                for(let i = 0; i < field.maxLength; ++i) {
                     const c = field.value[i]
                     if (c >= '0' && c <= '1') {
                         filtered += c
@@ -137,9 +162,7 @@ function ChangeLayout(layout) {
 
             // bin submit
             field.onchange = () => {
-                if (field.value.length < 4) {
-                    field.value = '0'.repeat(4 - field.value.length) + field.value
-                }
+                field.value = field.value.padStart(field.maxLength, '0')
                 SetDataFromBin()
             }
 
@@ -153,7 +176,8 @@ function ChangeLayout(layout) {
     {
         let fields = []
 
-        const N = layout.get_size() / 16
+        const digits = Math.ceil(layout.get_size() / 4)
+        const N = Math.ceil(digits / 4)
 
         for(let i = 0; i < N; ++i) {
             var field = document.createElement("input")
@@ -161,11 +185,12 @@ function ChangeLayout(layout) {
             field.type = 'text'
             field.id = 'hex-input-field-' + N
             field.classList.add('hex-input-field')
+            field.maxLength = Math.min(4, digits - i * 4)
 
             // hex edit
             field.oninput = () => {
                 let filtered = ''
-                for(let i = 0; i<4; ++i) {
+                for(let i = 0; i < field.maxLength; ++i) {
                     const c = field.value[i]
                     if (c >= '0' && c <= '9') {
                         filtered += c
@@ -181,9 +206,7 @@ function ChangeLayout(layout) {
 
             // hex submit
             field.onchange = () => {
-                if (field.value.length < 4) {
-                    field.value = '0'.repeat(4 - field.value.length) + field.value
-                }
+                field.value = field.value.padStart(field.maxLength, '0')
                 field.value = field.value.toUpperCase()
                 SetDataFromHex()
             }
@@ -195,13 +218,19 @@ function ChangeLayout(layout) {
     }
 }
 
-// dec edit
+// dec edit, anything the filter lets through is checked by the parser on submit
 decInputField.oninput = () => {
     let filtered = ''
     for(let c of decInputField.value) {
         if (c >= '0' && c <= '9') {
             filtered += c
-        } else if (c == '.' || c == ',' || c == '-') {
+        } else if ('.,-+eE'.includes(c)) {
+            filtered += c
+        } else if ('xXpPabcdfABCDF'.includes(c)) {
+            // hex floats such as 0x1.8p+1
+            filtered += c
+        } else if (c == '/' || c == '^') {
+            // fractions and powers such as 1/3 and 2^-126
             filtered += c
         }
     }
@@ -218,7 +247,8 @@ decInputField.onchange = () => {
         if(decInputField.value.includes(',')) {
             decInputField.value.replace(',', '.')
         }
-        if (!decInputField.value.includes('.')) {
+        // only plain integers, 1e5.0 would not parse
+        if (/^[+-]?[0-9]+$/.test(decInputField.value)) {
             decInputField.value += '.0'
         }
     }
@@ -275,8 +305,21 @@ function SetBitsToCheckboxes(bits) {
     }
 }
 
+// the wasm functions throw their errors, which are shown next to the decimal input
+function ShowError(e) {
+    decErrorText.textContent = e.message ?? String(e)
+}
+
 function SetBitsToLabels(bits) {
-    const info = binary_to_decimal_ext(bits, current_layout, DECIMAL_PRECISION)
+    let info
+    try {
+        info = binary_to_decimal_ext(bits, current_layout, DECIMAL_PRECISION)
+    } catch (e) {
+        ShowError(e)
+        exponentBitsText.innerHTML = ''
+        mantissaBitsText.innerHTML = ''
+        return
+    }
     signBitText.innerHTML = info.is_positive ? '+' : '-'
 
     if (info.are_exponent_and_mantissa_valid) {
@@ -329,11 +372,22 @@ function SetBitsToHex(bits) {
 }
 
 function GetBitsFromDec() {
-    return decimal_to_binary(decInputField.value, current_layout)
+    try {
+        return decimal_to_binary(decInputField.value, current_layout)
+    } catch (e) {
+        ShowError(e)
+        return null
+    }
 }
 
 function SetBitsToDec(bits) {
-    let val = binary_to_decimal(bits, current_layout, DECIMAL_PRECISION)
+    let val
+    try {
+        val = binary_to_decimal(bits, current_layout, DECIMAL_PRECISION)
+    } catch (e) {
+        ShowError(e)
+        return
+    }
     if (!val.includes('NaN') && !val.includes('Infinity')) {
         if (!val.includes('.') && !val.includes(',')) {
             val += '.0'
@@ -342,15 +396,24 @@ function SetBitsToDec(bits) {
     decInputField.value = val
 }
 
+// BigInt keeps every bit of layouts wider than 53 bits, the top hex digit of layouts whose
+// size is not a multiple of 4 holds fewer bits
 function hex2bin(hex) {
-    return (parseInt(hex, 16).toString(2)).padStart(current_layout.get_size(), '0');
+    const size = current_layout.get_size()
+    return BigInt('0x' + hex).toString(2).padStart(size, '0').slice(-size);
 }
 
 function bin2hex(bin) {
-    return (parseInt(bin, 2).toString(16)).padStart(current_layout.get_size() / 4, '0');
+    return BigInt('0b' + bin).toString(16).padStart(Math.ceil(current_layout.get_size() / 4), '0');
 }
 
 function SetData(bits, setFunctionsList) {
+    // an input that did not convert leaves every other field as it was
+    if (bits === null) {
+        return
+    }
+
+    decErrorText.textContent = ''
     for(const f of setFunctionsList) {
         f(bits)
     }
@@ -402,34 +465,37 @@ function SetDataFromHex() {
     ])
 }
 
+// the encodings of the buttons come from the layout itself, whatever its special values
+function SetDecimal(decimal) {
+    try {
+        SetAllData(decimal_to_binary(decimal, current_layout))
+    } catch (e) {
+        ShowError(e)
+    }
+}
+
 function SetZero() {
-    let bin = '0'.repeat(current_layout.get_size())
-    SetAllData(bin)
+    SetDecimal('0')
 }
 
 function SetInf() {
-    let bin = '0'.repeat(current_layout.get_sign_size()) + '1'.repeat(current_layout.get_exponent_size()) + '0'.repeat(current_layout.get_mantissa_size())
-    SetAllData(bin)
+    SetDecimal('inf')
 }
 
 function SetNan() {
-    let bin = '0'.repeat(current_layout.get_sign_size()) + '1'.repeat(current_layout.get_exponent_size() + 1) + '0'.repeat(current_layout.get_mantissa_size() - 1)
-    SetAllData(bin)
+    SetDecimal('nan')
 }
 
 function SetOne() {
-    let bin = '0'.repeat(current_layout.get_sign_size() + 1) + '1'.repeat(current_layout.get_exponent_size() - 1) + '0'.repeat(current_layout.get_mantissa_size())
-    SetAllData(bin)
+    SetDecimal('1')
 }
 
 function SetTwo() {
-    let bin = '0'.repeat(current_layout.get_sign_size()) + '1' + '0'.repeat(current_layout.get_exponent_size() - 1) + '0'.repeat(current_layout.get_mantissa_size())
-    SetAllData(bin)
+    SetDecimal('2')
 }
 
 function SetThree() {
-    let bin = '0'.repeat(current_layout.get_sign_size()) + '1' + '0'.repeat(current_layout.get_exponent_size() - 1) + '1' + '0'.repeat(current_layout.get_mantissa_size() - 1)
-    SetAllData(bin)
+    SetDecimal('3')
 }
 
 ChangeLayout(DEFALT_LAYOUT)